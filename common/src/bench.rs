@@ -0,0 +1,116 @@
+//! Timing of input parsing and solving, shared by `ec bench` and the quests' `cargo bench`.
+
+use std::{
+    hint::black_box,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use crate::{Error, Input, Part, Solution, part_params, solve_input};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min: Duration,
+    /// The upper median for an even number of iterations.
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// `None` without samples.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Option<Self> {
+        samples.sort_unstable();
+        Some(Stats {
+            iterations: samples.len(),
+            min: *samples.first()?,
+            median: samples[samples.len() / 2],
+            max: *samples.last()?,
+        })
+    }
+}
+
+/// Runs `f` `iterations` times (at least once), stopping at the first error.
+pub fn time<T>(iterations: usize, mut f: impl FnMut() -> Result<T, Error>) -> Result<Stats, Error> {
+    let mut samples = Vec::with_capacity(iterations.max(1));
+    for _ in 0..iterations.max(1) {
+        let start = Instant::now();
+        black_box(f()?);
+        samples.push(start.elapsed());
+    }
+    Ok(Stats::from_samples(samples).expect("at least one sample"))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartTiming {
+    pub part: Part,
+    pub answer: String,
+    /// [`Solution::parse_input`] on text already in memory, so file IO isn't counted.
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+/// Times parsing `file_path` and solving `part` with the `params` overrides.
+pub fn bench_part<S: Solution>(
+    file_path: &str,
+    part: Part,
+    params: &[(String, String)],
+    iterations: usize,
+) -> Result<PartTiming, Error> {
+    let input = Input::open(file_path)?;
+    let part_params = part_params::<S>(part, params)?;
+    let parse = time(iterations, || S::parse_input(&input))?;
+    let parsed = S::parse_input(&input)?;
+    let answer = solve_input::<S>(&parsed, part, &part_params)?;
+    let solve = time(iterations, || solve_input::<S>(&parsed, part, &part_params))?;
+    Ok(PartTiming {
+        part,
+        answer,
+        parse,
+        solve,
+    })
+}
+
+/// `stage  min 1.2ms  median 1.3ms  max 2.0ms`, aligned across rows.
+pub fn format_row(stage: &str, stats: &Stats) -> String {
+    format!(
+        "{:<6} min {:>10.1?}  median {:>10.1?}  max {:>10.1?}",
+        stage, stats.min, stats.median, stats.max
+    )
+}
+
+/// A bench input: the part, its file, and the `(key, value)` params an example needs, such
+/// as a smaller count than the real input's default.
+pub type Case<'a> = (Part, &'a str, &'a [(&'a str, &'a str)]);
+
+/// Body of a quest's `benches/parts.rs`: times each case whose file exists, with its params
+/// overriding the part's defaults as `ec bench --param` would.
+pub fn main<S: Solution>(cases: &[Case], iterations: usize) {
+    for (part, path, params) in cases {
+        let label = if params.is_empty() {
+            format!("{}, part {}", path, part)
+        } else {
+            let params = params
+                .iter()
+                .map(|(key, value)| format!("{}={}", key, value))
+                .collect::<Vec<_>>();
+            format!("{}, part {} ({})", path, part, params.join(", "))
+        };
+        if !Path::new(path).is_file() {
+            println!("{}: skipped, input missing", label);
+            continue;
+        }
+        let params = params
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect::<Vec<_>>();
+        match bench_part::<S>(path, *part, &params, iterations) {
+            Ok(timing) => {
+                println!("{}: {}", label, timing.answer);
+                println!("  {}", format_row("parse", &timing.parse));
+                println!("  {}", format_row("solve", &timing.solve));
+            }
+            Err(e) => println!("{}: {}", label, e),
+        }
+    }
+}
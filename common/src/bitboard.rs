@@ -0,0 +1,297 @@
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign};
+
+use crate::grid::{Grid, Position};
+
+/// Set of cells of a `rows`x`columns` board, one bit per cell in row-major order.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Bitboard {
+    rows: usize,
+    columns: usize,
+    words: Vec<u64>,
+}
+
+impl Bitboard {
+    pub fn new(rows: usize, columns: usize) -> Self {
+        Bitboard {
+            rows,
+            columns,
+            words: vec![0; (rows * columns).div_ceil(64)],
+        }
+    }
+
+    /// Every cell of the board.
+    pub fn full(rows: usize, columns: usize) -> Self {
+        let mut result = Bitboard {
+            rows,
+            columns,
+            words: vec![u64::MAX; (rows * columns).div_ceil(64)],
+        };
+        result.trim();
+        result
+    }
+
+    pub fn from_grid<T>(grid: &Grid<T>, mut predicate: impl FnMut(&T) -> bool) -> Self {
+        let mut result = Bitboard::new(grid.rows(), grid.columns());
+        for (index, cell) in grid.cells().iter().enumerate() {
+            if predicate(cell) {
+                result.words[index / 64] |= 1 << (index % 64);
+            }
+        }
+        result
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    fn bit(&self, (row, column): Position) -> usize {
+        assert!(
+            row < self.rows && column < self.columns,
+            "{:?} is outside of a {}x{} bitboard",
+            (row, column),
+            self.rows,
+            self.columns
+        );
+        row * self.columns + column
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        let bit = self.bit(position);
+        self.words[bit / 64] & (1 << (bit % 64)) != 0
+    }
+
+    pub fn insert(&mut self, position: Position) {
+        let bit = self.bit(position);
+        self.words[bit / 64] |= 1 << (bit % 64);
+    }
+
+    pub fn remove(&mut self, position: Position) {
+        let bit = self.bit(position);
+        self.words[bit / 64] &= !(1 << (bit % 64));
+    }
+
+    pub fn count(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&word| word == 0)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Position> + '_ {
+        self.words
+            .iter()
+            .enumerate()
+            .flat_map(|(word_index, &word)| {
+                let mut word = word;
+                std::iter::from_fn(move || {
+                    (word != 0).then(|| {
+                        let bit = word.trailing_zeros() as usize;
+                        word &= word - 1;
+                        word_index * 64 + bit
+                    })
+                })
+            })
+            .map(|bit| (bit / self.columns, bit % self.columns))
+    }
+
+    /// Cells in `self` but not in `other`.
+    pub fn and_not(&self, other: &Bitboard) -> Bitboard {
+        self.assert_same_shape(other);
+        Bitboard {
+            rows: self.rows,
+            columns: self.columns,
+            words: self
+                .words
+                .iter()
+                .zip(&other.words)
+                .map(|(a, b)| a & !b)
+                .collect(),
+        }
+    }
+
+    /// Moves every bit `bits` places along the row-major order, dropping the ones that leave
+    /// the board. Bits crossing a row edge wrap into the next row; [`MoveSet`] masks those out.
+    pub fn shifted(&self, bits: isize) -> Bitboard {
+        let length = self.words.len();
+        let distance = bits.unsigned_abs();
+        let (word_shift, bit_shift) = (distance / 64, distance % 64);
+        let mut words = vec![0; length];
+        if bits >= 0 {
+            for (index, word) in words.iter_mut().enumerate().skip(word_shift) {
+                let source = index - word_shift;
+                *word = self.words[source] << bit_shift;
+                if bit_shift > 0 && source > 0 {
+                    *word |= self.words[source - 1] >> (64 - bit_shift);
+                }
+            }
+        } else {
+            for (index, word) in words
+                .iter_mut()
+                .enumerate()
+                .take(length.saturating_sub(word_shift))
+            {
+                let source = index + word_shift;
+                *word = self.words[source] >> bit_shift;
+                if bit_shift > 0 && source + 1 < length {
+                    *word |= self.words[source + 1] << (64 - bit_shift);
+                }
+            }
+        }
+        let mut result = Bitboard {
+            rows: self.rows,
+            columns: self.columns,
+            words,
+        };
+        result.trim();
+        result
+    }
+
+    /// Clears the padding bits past the last cell.
+    fn trim(&mut self) {
+        let cells = self.rows * self.columns;
+        if !cells.is_multiple_of(64)
+            && let Some(last) = self.words.last_mut()
+        {
+            *last &= (1 << (cells % 64)) - 1;
+        }
+    }
+
+    fn assert_same_shape(&self, other: &Bitboard) {
+        assert!(
+            self.rows == other.rows && self.columns == other.columns,
+            "Can't combine a {}x{} bitboard with a {}x{} one",
+            self.rows,
+            self.columns,
+            other.rows,
+            other.columns
+        );
+    }
+}
+
+impl BitAnd for &Bitboard {
+    type Output = Bitboard;
+
+    fn bitand(self, other: &Bitboard) -> Bitboard {
+        let mut result = self.clone();
+        result &= other;
+        result
+    }
+}
+
+impl BitOr for &Bitboard {
+    type Output = Bitboard;
+
+    fn bitor(self, other: &Bitboard) -> Bitboard {
+        let mut result = self.clone();
+        result |= other;
+        result
+    }
+}
+
+impl BitAndAssign<&Bitboard> for Bitboard {
+    fn bitand_assign(&mut self, other: &Bitboard) {
+        self.assert_same_shape(other);
+        self.words
+            .iter_mut()
+            .zip(&other.words)
+            .for_each(|(a, b)| *a &= b);
+    }
+}
+
+impl BitOrAssign<&Bitboard> for Bitboard {
+    fn bitor_assign(&mut self, other: &Bitboard) {
+        self.assert_same_shape(other);
+        self.words
+            .iter_mut()
+            .zip(&other.words)
+            .for_each(|(a, b)| *a |= b);
+    }
+}
+
+/// A list of `(row, column)` offsets compiled for one board size, so that a single move of a
+/// whole frontier is a shift and a mask per offset.
+#[derive(Debug, Clone)]
+pub struct MoveSet {
+    shifts: Vec<(isize, Bitboard)>,
+}
+
+impl MoveSet {
+    pub fn new(rows: usize, columns: usize, offsets: &[(isize, isize)]) -> Self {
+        let shifts = offsets
+            .iter()
+            .map(|&(row_offset, column_offset)| {
+                // Targets whose source cell is on the board; everything else is a wrapped bit.
+                let mut mask = Bitboard::new(rows, columns);
+                for row in 0..rows {
+                    for column in 0..columns {
+                        let source_row = row.checked_add_signed(-row_offset);
+                        let source_column = column.checked_add_signed(-column_offset);
+                        if source_row.is_some_and(|source_row| source_row < rows)
+                            && source_column.is_some_and(|source_column| source_column < columns)
+                        {
+                            mask.insert((row, column));
+                        }
+                    }
+                }
+                (row_offset * columns as isize + column_offset, mask)
+            })
+            .collect();
+        MoveSet { shifts }
+    }
+
+    /// Every cell one move away from some cell of `frontier`.
+    pub fn step(&self, frontier: &Bitboard) -> Bitboard {
+        let mut result = Bitboard::new(frontier.rows, frontier.columns);
+        for (bits, mask) in &self.shifts {
+            let mut moved = frontier.shifted(*bits);
+            moved &= mask;
+            result |= &moved;
+        }
+        result
+    }
+}
+
+/// Cells reachable from a start set, for every step count from 0 up to `steps()`.
+#[derive(Debug, Clone)]
+pub struct Reachable {
+    exact: Vec<Bitboard>,
+    cumulative: Vec<Bitboard>,
+}
+
+impl Reachable {
+    pub fn steps(&self) -> usize {
+        self.exact.len() - 1
+    }
+
+    /// Cells a piece can stand on after exactly `step` moves.
+    pub fn exact(&self, step: usize) -> &Bitboard {
+        &self.exact[step]
+    }
+
+    /// Cells a piece can stand on after at most `step` moves.
+    pub fn within(&self, step: usize) -> &Bitboard {
+        &self.cumulative[step]
+    }
+}
+
+/// Runs `steps` moves from `start` with the given offsets, e.g. [`crate::grid::KNIGHT_MOVES`],
+/// in O(steps * offsets * cells / 64).
+pub fn reachable(start: &Bitboard, offsets: &[(isize, isize)], steps: usize) -> Reachable {
+    let moves = MoveSet::new(start.rows, start.columns, offsets);
+    let mut exact = vec![start.clone()];
+    let mut cumulative = vec![start.clone()];
+    for step in 0..steps {
+        let next = moves.step(&exact[step]);
+        cumulative.push(&cumulative[step] | &next);
+        exact.push(next);
+    }
+    Reachable { exact, cumulative }
+}
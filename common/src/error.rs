@@ -0,0 +1,172 @@
+use std::{
+    fmt, io,
+    path::{Path, PathBuf},
+};
+
+/// Where in the input a problem was found, with enough context to point at it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    pub path: Option<PathBuf>,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    pub token: String,
+    pub line_text: String,
+}
+
+impl Location {
+    /// Locates `token` inside `input`. `token` has to be a subslice of `input` (what `split`,
+    /// `lines` and `trim` hand out); anything else is reported at the start of the input.
+    pub fn of(input: &str, token: &str) -> Self {
+        let start = input.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|offset| offset + token.len() <= input.len())
+            .unwrap_or(0);
+
+        let line_start = input[..offset].rfind('\n').map_or(0, |index| index + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |index| offset + index);
+
+        Location {
+            path: None,
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            token: token.to_string(),
+            line_text: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.path {
+            Some(path) => write!(f, "{}:{}:{}", path.display(), self.line, self.column),
+            None => write!(f, "{}:{}", self.line, self.column),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    /// The input couldn't be read at all.
+    Io { path: PathBuf, source: io::Error },
+    /// The input doesn't have the expected shape, e.g. a missing separator or section.
+    Format { location: Location, message: String },
+    /// A token is in the right place but its value isn't allowed, e.g. an unknown symbol.
+    InvalidValue { location: Location, message: String },
+    /// A part parameter or command line value is unknown or malformed.
+    Param { key: String, message: String },
+    /// The input parsed fine but the puzzle has no answer for it.
+    Unsolvable(String),
+}
+
+impl Error {
+    pub fn io(path: impl AsRef<Path>, source: io::Error) -> Self {
+        Error::Io {
+            path: path.as_ref().to_path_buf(),
+            source,
+        }
+    }
+
+    pub fn format(input: &str, token: &str, message: impl Into<String>) -> Self {
+        Error::Format {
+            location: Location::of(input, token),
+            message: message.into(),
+        }
+    }
+
+    pub fn invalid_value(input: &str, token: &str, message: impl Into<String>) -> Self {
+        Error::InvalidValue {
+            location: Location::of(input, token),
+            message: message.into(),
+        }
+    }
+
+    pub fn param(key: &str, message: impl Into<String>) -> Self {
+        Error::Param {
+            key: key.to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn unsolvable(message: impl Into<String>) -> Self {
+        Error::Unsolvable(message.into())
+    }
+
+    /// Records which file the located error came from.
+    pub fn with_path(mut self, path: impl AsRef<Path>) -> Self {
+        if let Error::Format { location, .. } | Error::InvalidValue { location, .. } = &mut self {
+            location.path = Some(path.as_ref().to_path_buf());
+        }
+        self
+    }
+
+    pub fn location(&self) -> Option<&Location> {
+        match self {
+            Error::Format { location, .. } | Error::InvalidValue { location, .. } => Some(location),
+            _ => None,
+        }
+    }
+
+    /// Multi-line rendering with the offending line and a caret under the token.
+    pub fn diagnostic(&self) -> String {
+        let Some(location) = self.location() else {
+            return format!("error: {}", self);
+        };
+        let line_number = location.line.to_string();
+        let gutter = " ".repeat(line_number.len());
+        format!(
+            "error: {}\n{}--> {}\n{} |\n{} | {}\n{} | {}{}",
+            self.message(),
+            gutter,
+            location,
+            gutter,
+            line_number,
+            location.line_text,
+            gutter,
+            " ".repeat(location.column - 1),
+            "^".repeat(
+                location
+                    .token
+                    .lines()
+                    .next()
+                    .map_or(1, |line| line.chars().count().max(1))
+            ),
+        )
+    }
+
+    fn message(&self) -> String {
+        match self {
+            Error::Io { path, source } => format!("failed to read {}: {}", path.display(), source),
+            Error::Format { message, .. } => format!("invalid format: {}", message),
+            Error::InvalidValue { location, message } => {
+                format!("invalid value \"{}\": {}", location.token, message)
+            }
+            Error::Param { key, message } => format!("invalid param {}: {}", key, message),
+            Error::Unsolvable(message) => message.clone(),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.location() {
+            Some(location) => write!(f, "{} at {}", self.message(), location),
+            None => write!(f, "{}", self.message()),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,251 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::Error;
+
+pub type Position = (usize, usize);
+
+pub const ORTHOGONAL_MOVES: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+pub const ALL_DIRECTION_MOVES: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+];
+
+pub const KNIGHT_MOVES: [(isize, isize); 8] = [
+    (-2, -1),
+    (-2, 1),
+    (-1, 2),
+    (1, 2),
+    (2, -1),
+    (2, 1),
+    (-1, -2),
+    (1, -2),
+];
+
+pub const CAMEL_MOVES: [(isize, isize); 8] = [
+    (-3, -1),
+    (-3, 1),
+    (-1, 3),
+    (1, 3),
+    (3, -1),
+    (3, 1),
+    (-1, -3),
+    (1, -3),
+];
+
+/// Dense row-major 2D grid addressed by `(row, column)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    rows: usize,
+    columns: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Parses a character map, one row per line. `parse_cell` turns a character into a cell
+    /// or says what it expected instead; that message is reported at the character's location.
+    /// Carriage returns ending a line aren't cells, so CRLF maps parse like LF ones.
+    pub fn parse(
+        input: &str,
+        mut parse_cell: impl FnMut(char) -> Result<T, String>,
+    ) -> Result<Self, Error> {
+        let mut columns = None;
+        let mut rows = 0;
+        let mut cells = Vec::new();
+        for line in input.trim().lines() {
+            let line = line.trim_end_matches('\r');
+            let mut line_columns = 0;
+            for (offset, symbol) in line.char_indices() {
+                let cell = parse_cell(symbol).map_err(|message| {
+                    Error::invalid_value(input, &line[offset..offset + symbol.len_utf8()], message)
+                })?;
+                cells.push(cell);
+                line_columns += 1;
+            }
+            match columns {
+                None => columns = Some(line_columns),
+                Some(columns) if columns != line_columns => {
+                    return Err(Error::format(
+                        input,
+                        line,
+                        format!("expected {} columns, got {}", columns, line_columns),
+                    ));
+                }
+                Some(_) => {}
+            }
+            rows += 1;
+        }
+        let columns =
+            columns.ok_or_else(|| Error::format(input, input, "expected at least one row"))?;
+        Ok(Grid {
+            rows,
+            columns,
+            cells,
+        })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, (row, column): Position) -> bool {
+        row < self.rows && column < self.columns
+    }
+
+    /// Row-major index of `position` into `cells`.
+    pub fn index_of(&self, (row, column): Position) -> usize {
+        row * self.columns + column
+    }
+
+    pub fn position_of(&self, index: usize) -> Position {
+        (index / self.columns, index % self.columns)
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[self.index_of(position)])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        if self.contains(position) {
+            let index = self.index_of(position);
+            Some(&mut self.cells[index])
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.columns..(row + 1) * self.columns]
+    }
+
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(column).step_by(self.columns)
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> + use<T> {
+        let columns = self.columns;
+        (0..self.rows * columns).map(move |index| (index / columns, index % columns))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn find(&self, predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|index| self.position_of(index))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            rows: self.rows,
+            columns: self.columns,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Positions reached by applying each `(row, column)` offset to `position`, skipping the
+    /// ones that fall off the grid.
+    pub fn moves<'a>(
+        &self,
+        (row, column): Position,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = Position> + use<'a, T> {
+        let (rows, columns) = (self.rows, self.columns);
+        offsets
+            .iter()
+            .filter_map(move |&(row_offset, column_offset)| {
+                let next_row = row.checked_add_signed(row_offset)?;
+                let next_column = column.checked_add_signed(column_offset)?;
+                (next_row < rows && next_column < columns).then_some((next_row, next_column))
+            })
+    }
+
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + use<T> {
+        self.moves(position, &ORTHOGONAL_MOVES)
+    }
+
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + use<T> {
+        self.moves(position, &ALL_DIRECTION_MOVES)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(rows: usize, columns: usize, value: T) -> Self {
+        Grid {
+            rows,
+            columns,
+            cells: vec![value; rows * columns],
+        }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        assert!(
+            self.contains(position),
+            "{:?} is outside of a {}x{} grid",
+            position,
+            self.rows,
+            self.columns
+        );
+        &self.cells[self.index_of(position)]
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        assert!(
+            self.contains(position),
+            "{:?} is outside of a {}x{} grid",
+            position,
+            self.rows,
+            self.columns
+        );
+        let index = self.index_of(position);
+        &mut self.cells[index]
+    }
+}
+
+/// Prints one line per row with the cells' own `Display` side by side.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in 0..self.rows {
+            for cell in self.row(row) {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
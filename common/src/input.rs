@@ -0,0 +1,170 @@
+use std::{
+    fmt, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use crate::Error;
+
+/// A puzzle input read once. Everything handed out borrows from it, so parsers can split it
+/// into sections, lines and fields without copying, and errors can point back into it.
+pub struct Input {
+    path: Option<PathBuf>,
+    data: Data,
+}
+
+enum Data {
+    Owned(String),
+    #[cfg(feature = "mmap")]
+    Mapped(memmap2::Mmap),
+}
+
+impl Input {
+    /// Maps the file when built with the `mmap` feature, reads it otherwise. `-` reads stdin.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, Error> {
+        if path.as_ref() == Path::new("-") {
+            return Input::from_reader(io::stdin().lock());
+        }
+        #[cfg(feature = "mmap")]
+        return Input::map(path);
+        #[cfg(not(feature = "mmap"))]
+        Input::read(path)
+    }
+
+    pub fn read(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        Ok(Input {
+            path: Some(path.to_path_buf()),
+            data: Data::Owned(fs::read_to_string(path).map_err(|e| Error::io(path, e))?),
+        })
+    }
+
+    pub fn from_reader(mut reader: impl Read) -> Result<Self, Error> {
+        let mut text = String::new();
+        reader
+            .read_to_string(&mut text)
+            .map_err(|e| Error::io("<input>", e))?;
+        Ok(Input::from(text))
+    }
+
+    /// Like [`Input::read`], but maps the file instead of copying it into memory.
+    #[cfg(feature = "mmap")]
+    pub fn map(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let file = fs::File::open(path).map_err(|e| Error::io(path, e))?;
+        // SAFETY: the map is read-only; puzzle inputs aren't modified while a quest runs.
+        let map = unsafe { memmap2::Mmap::map(&file) }.map_err(|e| Error::io(path, e))?;
+        std::str::from_utf8(&map)
+            .map_err(|e| Error::io(path, io::Error::new(io::ErrorKind::InvalidData, e)))?;
+        Ok(Input {
+            path: Some(path.to_path_buf()),
+            data: Data::Mapped(map),
+        })
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// The whole file, untouched.
+    pub fn text(&self) -> &str {
+        match &self.data {
+            Data::Owned(text) => text,
+            // Checked to be UTF-8 in `map`.
+            #[cfg(feature = "mmap")]
+            Data::Mapped(map) => unsafe { std::str::from_utf8_unchecked(map) },
+        }
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        self.text().as_bytes()
+    }
+
+    /// The file without leading and trailing whitespace, which is what most quests parse.
+    pub fn trimmed(&self) -> &str {
+        self.text().trim()
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.trimmed().lines()
+    }
+
+    pub fn sections(&self) -> impl Iterator<Item = &str> {
+        sections(self.trimmed())
+    }
+
+    /// An [`Error::Format`] pointing at `token`, which must borrow from this input.
+    pub fn format_error(&self, token: &str, message: impl Into<String>) -> Error {
+        self.with_path(Error::format(self.text(), token, message))
+    }
+
+    /// An [`Error::InvalidValue`] pointing at `token`, which must borrow from this input.
+    pub fn invalid_value(&self, token: &str, message: impl Into<String>) -> Error {
+        self.with_path(Error::invalid_value(self.text(), token, message))
+    }
+
+    /// Parses `token` with `FromStr`, reporting failures as "invalid {what}: {error}".
+    pub fn parse<T: FromStr>(&self, token: &str, what: &str) -> Result<T, Error>
+    where
+        T::Err: fmt::Display,
+    {
+        token
+            .parse::<T>()
+            .map_err(|e| self.invalid_value(token, format!("invalid {}: {}", what, e)))
+    }
+
+    /// Attaches this input's path to an error located inside it, e.g. one from [`crate::grid::Grid::parse`].
+    pub fn with_path(&self, error: Error) -> Error {
+        match &self.path {
+            Some(path) => error.with_path(path),
+            None => error,
+        }
+    }
+}
+
+impl From<&str> for Input {
+    fn from(text: &str) -> Self {
+        Input::from(text.to_string())
+    }
+}
+
+impl From<String> for Input {
+    fn from(text: String) -> Self {
+        Input {
+            path: None,
+            data: Data::Owned(text),
+        }
+    }
+}
+
+/// Blocks of lines separated by one or more blank lines, each without its trailing newline.
+pub fn sections(text: &str) -> impl Iterator<Item = &str> {
+    let mut rest = text;
+    std::iter::from_fn(move || {
+        let mut start = None;
+        let mut end = 0;
+        let mut consumed = 0;
+        for line in rest.split_inclusive('\n') {
+            let blank = line.trim().is_empty();
+            match (start, blank) {
+                (None, true) => {}
+                (None, false) => {
+                    start = Some(consumed);
+                    end = consumed + line.trim_end().len();
+                }
+                (Some(_), false) => end = consumed + line.trim_end().len(),
+                (Some(_), true) => break,
+            }
+            consumed += line.len();
+        }
+        let section = &rest[start?..end];
+        rest = &rest[consumed..];
+        Some(section)
+    })
+}
+
+/// `line` split at `separator`, each field trimmed.
+pub fn fields(line: &str, separator: char) -> impl Iterator<Item = &str> {
+    line.split(separator).map(str::trim)
+}
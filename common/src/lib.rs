@@ -0,0 +1,179 @@
+use std::{env, fmt, io::Read, process, str::FromStr};
+
+pub mod bench;
+pub mod bitboard;
+mod error;
+pub mod grid;
+pub mod input;
+
+pub use error::{Error, Location};
+pub use input::Input;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+    Three,
+}
+
+impl Part {
+    pub const ALL: [Part; 3] = [Part::One, Part::Two, Part::Three];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+            Part::Three => 3,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            3 => Ok(Part::Three),
+            _ => Err(Error::param(
+                "part",
+                format!("expected 1, 2 or 3, got {}", value),
+            )),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_param::<u8>("part", s).and_then(Part::try_from)
+    }
+}
+
+/// Extra knobs a part needs besides its input, e.g. the nail count in quest 8.
+/// `for_part` returns the values the real puzzle uses, `set` overrides one by name.
+pub trait Params: Sized {
+    fn for_part(part: Part) -> Self;
+
+    fn set(&mut self, key: &str, value: &str) -> Result<(), Error>;
+}
+
+impl Params for () {
+    fn for_part(_part: Part) -> Self {}
+
+    fn set(&mut self, key: &str, _value: &str) -> Result<(), Error> {
+        Err(Error::param(key, "unknown param"))
+    }
+}
+
+pub fn parse_param<T: FromStr>(key: &str, value: &str) -> Result<T, Error>
+where
+    T::Err: fmt::Display,
+{
+    value
+        .trim()
+        .parse::<T>()
+        .map_err(|e| Error::param(key, format!("\"{}\": {}", value, e)))
+}
+
+pub trait Solution {
+    type Input;
+    type Params: Params;
+    type Answer1: fmt::Display;
+    type Answer2: fmt::Display;
+    type Answer3: fmt::Display;
+
+    fn parse_input(input: &Input) -> Result<Self::Input, Error>;
+
+    fn parse(file_path: &str) -> Result<Self::Input, Error> {
+        Self::parse_input(&Input::open(file_path)?)
+    }
+
+    fn parse_str(text: &str) -> Result<Self::Input, Error> {
+        Self::parse_input(&Input::from(text))
+    }
+
+    fn parse_reader(reader: impl Read) -> Result<Self::Input, Error> {
+        Self::parse_input(&Input::from_reader(reader)?)
+    }
+
+    fn part1(input: &Self::Input, params: &Self::Params) -> Result<Self::Answer1, Error>;
+
+    fn part2(input: &Self::Input, params: &Self::Params) -> Result<Self::Answer2, Error>;
+
+    fn part3(input: &Self::Input, params: &Self::Params) -> Result<Self::Answer3, Error>;
+}
+
+/// Parses `file_path`, applies the `params` overrides on top of the part's defaults
+/// and returns the rendered answer.
+pub fn solve<S: Solution>(
+    file_path: &str,
+    part: Part,
+    params: &[(String, String)],
+) -> Result<String, Error> {
+    let part_params = part_params::<S>(part, params)?;
+    solve_input::<S>(&S::parse(file_path)?, part, &part_params)
+}
+
+pub fn part_params<S: Solution>(
+    part: Part,
+    params: &[(String, String)],
+) -> Result<S::Params, Error> {
+    let mut part_params = S::Params::for_part(part);
+    for (key, value) in params {
+        part_params.set(key, value)?;
+    }
+    Ok(part_params)
+}
+
+pub fn solve_input<S: Solution>(
+    input: &S::Input,
+    part: Part,
+    params: &S::Params,
+) -> Result<String, Error> {
+    match part {
+        Part::One => S::part1(input, params).map(|answer| answer.to_string()),
+        Part::Two => S::part2(input, params).map(|answer| answer.to_string()),
+        Part::Three => S::part3(input, params).map(|answer| answer.to_string()),
+    }
+}
+
+/// Command line of the quest binaries: `questN <PATH|-> [PART]...`, where `-` reads stdin
+/// and no parts means all three. Returns `false` when there are no arguments, so `main` can
+/// fall back to its built-in inputs.
+pub fn run_from_args<S: Solution>() -> bool {
+    let args = env::args().skip(1).collect::<Vec<String>>();
+    let Some((path, parts)) = args.split_first() else {
+        return false;
+    };
+    if let Err(e) = run_parts::<S>(path, parts) {
+        eprintln!("{}", e.diagnostic());
+        process::exit(1);
+    }
+    true
+}
+
+fn run_parts<S: Solution>(path: &str, parts: &[String]) -> Result<(), Error> {
+    let parts = if parts.is_empty() {
+        Part::ALL.to_vec()
+    } else {
+        parts
+            .iter()
+            .map(|part| part.parse())
+            .collect::<Result<Vec<Part>, Error>>()?
+    };
+    let input = S::parse(path)?;
+    for part in parts {
+        let answer = solve_input::<S>(&input, part, &S::Params::for_part(part))?;
+        println!("Part {}: {}", part, answer);
+    }
+    Ok(())
+}
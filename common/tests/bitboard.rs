@@ -0,0 +1,177 @@
+use std::collections::{BTreeSet, VecDeque};
+
+use common::{
+    bitboard::{Bitboard, MoveSet, reachable},
+    grid::{CAMEL_MOVES, Grid, KNIGHT_MOVES, ORTHOGONAL_MOVES, Position},
+};
+
+/// Board sizes whose cell counts aren't multiples of 64, including rows that straddle words.
+const BOARDS: [(usize, usize); 5] = [(1, 1), (3, 5), (7, 9), (5, 13), (3, 70)];
+
+fn board(rows: usize, columns: usize, positions: &[Position]) -> Bitboard {
+    let mut board = Bitboard::new(rows, columns);
+    for &position in positions {
+        board.insert(position);
+    }
+    board
+}
+
+fn positions(board: &Bitboard) -> BTreeSet<Position> {
+    board.iter().collect()
+}
+
+/// Cells where a walk of exactly `step` moves can end, by stepping every cell separately.
+fn brute_force_exact(
+    grid: &Grid<()>,
+    start: &[Position],
+    offsets: &[(isize, isize)],
+    step: usize,
+) -> BTreeSet<Position> {
+    let mut frontier = start.iter().copied().collect::<BTreeSet<Position>>();
+    for _ in 0..step {
+        frontier = frontier
+            .iter()
+            .flat_map(|&position| grid.moves(position, offsets))
+            .collect();
+    }
+    frontier
+}
+
+/// Cells at most `steps` moves away, by breadth-first search.
+fn brute_force_within(
+    grid: &Grid<()>,
+    start: &[Position],
+    offsets: &[(isize, isize)],
+    steps: usize,
+) -> BTreeSet<Position> {
+    let mut distances = Grid::filled(grid.rows(), grid.columns(), None);
+    let mut queue = VecDeque::new();
+    for &position in start {
+        distances[position] = Some(0);
+        queue.push_back(position);
+    }
+    while let Some(position) = queue.pop_front() {
+        let distance = distances[position].unwrap();
+        if distance == steps {
+            continue;
+        }
+        for next in grid.moves(position, offsets) {
+            if distances[next].is_none() {
+                distances[next] = Some(distance + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+    distances
+        .iter()
+        .filter(|(_, distance)| distance.is_some())
+        .map(|(position, _)| position)
+        .collect()
+}
+
+#[test]
+fn knight_moves_from_corners_and_edges_do_not_wrap() {
+    let moves = MoveSet::new(5, 13, &KNIGHT_MOVES);
+    let step = |position| positions(&moves.step(&board(5, 13, &[position])));
+
+    assert_eq!(step((0, 0)), BTreeSet::from([(1, 2), (2, 1)]));
+    assert_eq!(step((0, 12)), BTreeSet::from([(1, 10), (2, 11)]));
+    assert_eq!(step((4, 0)), BTreeSet::from([(2, 1), (3, 2)]));
+    assert_eq!(step((4, 12)), BTreeSet::from([(2, 11), (3, 10)]));
+    assert_eq!(
+        step((2, 12)),
+        BTreeSet::from([(0, 11), (1, 10), (3, 10), (4, 11)])
+    );
+    assert_eq!(
+        step((2, 1)),
+        BTreeSet::from([(0, 0), (0, 2), (1, 3), (3, 3), (4, 0), (4, 2)])
+    );
+}
+
+#[test]
+fn moves_across_word_boundaries() {
+    // Row 1 of a 70-wide board spans the first and second word.
+    let moves = MoveSet::new(3, 70, &KNIGHT_MOVES);
+    for position in [(0, 62), (0, 63), (0, 69), (1, 0), (1, 57), (2, 69)] {
+        let grid = Grid::filled(3, 70, ());
+        assert_eq!(
+            positions(&moves.step(&board(3, 70, &[position]))),
+            grid.moves(position, &KNIGHT_MOVES).collect(),
+            "{:?}",
+            position
+        );
+    }
+}
+
+#[test]
+fn step_matches_grid_moves_everywhere() {
+    for (rows, columns) in BOARDS {
+        let grid = Grid::filled(rows, columns, ());
+        for offsets in [&KNIGHT_MOVES[..], &ORTHOGONAL_MOVES, &CAMEL_MOVES] {
+            let moves = MoveSet::new(rows, columns, offsets);
+            for position in grid.positions() {
+                assert_eq!(
+                    positions(&moves.step(&board(rows, columns, &[position]))),
+                    grid.moves(position, offsets).collect(),
+                    "{:?} on {}x{}",
+                    position,
+                    rows,
+                    columns
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn exact_and_within_match_brute_force() {
+    for (rows, columns) in BOARDS {
+        let grid = Grid::filled(rows, columns, ());
+        let starts = [
+            vec![(0, 0)],
+            vec![(rows - 1, columns - 1)],
+            vec![(rows / 2, columns / 2), (0, columns - 1)],
+        ];
+        for start in &starts {
+            let reach = reachable(&board(rows, columns, start), &KNIGHT_MOVES, 6);
+            assert_eq!(reach.steps(), 6);
+            for step in 0..=6 {
+                let context = format!("{:?} on {}x{}, step {}", start, rows, columns, step);
+                assert_eq!(
+                    positions(reach.exact(step)),
+                    brute_force_exact(&grid, start, &KNIGHT_MOVES, step),
+                    "exact {}",
+                    context
+                );
+                assert_eq!(
+                    positions(reach.within(step)),
+                    brute_force_within(&grid, start, &KNIGHT_MOVES, step),
+                    "within {}",
+                    context
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn exact_alternates_colours_while_within_accumulates() {
+    // A knight always changes colour, so after an odd number of moves it can't be home.
+    let reach = reachable(&board(7, 9, &[(3, 4)]), &KNIGHT_MOVES, 3);
+    assert!(!reach.exact(1).contains((3, 4)));
+    assert!(reach.exact(2).contains((3, 4)));
+    assert!(!reach.exact(3).contains((3, 4)));
+    assert!(reach.within(3).contains((3, 4)));
+    assert_eq!(reach.within(0), reach.exact(0));
+    assert!(reach.within(2).count() > reach.exact(2).count());
+}
+
+#[test]
+fn full_boards_have_no_padding_bits() {
+    for (rows, columns) in BOARDS {
+        let full = Bitboard::full(rows, columns);
+        assert_eq!(full.count(), rows * columns);
+        assert_eq!(full.shifted(1).count(), rows * columns - 1);
+        assert_eq!(full.shifted(-1).count(), rows * columns - 1);
+    }
+}
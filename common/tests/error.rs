@@ -0,0 +1,110 @@
+use common::{Error, Location};
+
+const INPUT: &str = "alpha beta\ngamma delta\r\nepsilon";
+
+fn token<'a>(input: &'a str, needle: &str) -> &'a str {
+    let start = input.find(needle).unwrap();
+    &input[start..start + needle.len()]
+}
+
+#[test]
+fn locates_tokens_on_the_first_and_later_lines() {
+    let first = Location::of(INPUT, token(INPUT, "beta"));
+    assert_eq!((first.line, first.column), (1, 7));
+    assert_eq!(first.line_text, "alpha beta");
+
+    let second = Location::of(INPUT, token(INPUT, "delta"));
+    assert_eq!((second.line, second.column), (2, 7));
+    assert_eq!(second.line_text, "gamma delta");
+
+    let last = Location::of(INPUT, token(INPUT, "epsilon"));
+    assert_eq!((last.line, last.column), (3, 1));
+    assert_eq!(last.token, "epsilon");
+}
+
+#[test]
+fn locates_tokens_at_the_start_and_end_of_a_line() {
+    let start = Location::of(INPUT, token(INPUT, "gamma"));
+    assert_eq!((start.line, start.column), (2, 1));
+
+    let end = Location::of(INPUT, &INPUT[10..10]);
+    assert_eq!((end.line, end.column), (1, 11));
+    assert_eq!(end.line_text, "alpha beta");
+}
+
+#[test]
+fn tokens_from_elsewhere_are_reported_at_the_start() {
+    let elsewhere = "beta".to_string();
+    let location = Location::of(INPUT, &elsewhere);
+    assert_eq!((location.line, location.column), (1, 1));
+    assert_eq!(location.token, "beta");
+}
+
+#[test]
+fn diagnostic_points_a_caret_at_the_token() {
+    let error = Error::invalid_value(INPUT, token(INPUT, "delta"), "not a letter");
+    assert_eq!(
+        error.diagnostic(),
+        "error: invalid value \"delta\": not a letter\n \
+         --> 2:7\n  \
+         |\n\
+         2 | gamma delta\n  \
+         |       ^^^^^"
+    );
+
+    let error = Error::format(INPUT, token(INPUT, "alpha"), "expected a number");
+    assert_eq!(
+        error.diagnostic(),
+        "error: invalid format: expected a number\n \
+         --> 1:1\n  \
+         |\n\
+         1 | alpha beta\n  \
+         | ^^^^^"
+    );
+}
+
+#[test]
+fn diagnostic_widens_the_gutter_for_long_line_numbers() {
+    let input = "x\n".repeat(11) + "bad";
+    let error = Error::format(&input, token(&input, "bad"), "oops");
+    assert_eq!(
+        error.diagnostic(),
+        "error: invalid format: oops\n  \
+         --> 12:1\n   \
+         |\n\
+         12 | bad\n   \
+         | ^^^"
+    );
+}
+
+#[test]
+fn with_path_names_the_file_in_the_header() {
+    let error = Error::format(INPUT, token(INPUT, "delta"), "oops").with_path("notes/input.txt");
+    assert_eq!(
+        error.to_string(),
+        "invalid format: oops at notes/input.txt:2:7"
+    );
+    assert!(
+        error.diagnostic().contains("\n --> notes/input.txt:2:7\n"),
+        "{}",
+        error.diagnostic()
+    );
+
+    let unlocated = Error::unsolvable("no answer").with_path("notes/input.txt");
+    assert_eq!(unlocated.diagnostic(), "error: no answer");
+}
+
+#[test]
+fn columns_count_characters_not_bytes() {
+    let input = "ñandú über 7";
+    let error = Error::invalid_value(input, token(input, "über"), "not a number");
+    let location = error.location().unwrap();
+    assert_eq!((location.line, location.column), (1, 7));
+    assert!(
+        error
+            .diagnostic()
+            .ends_with("1 | ñandú über 7\n  |       ^^^^"),
+        "{}",
+        error.diagnostic()
+    );
+}
@@ -0,0 +1,100 @@
+use common::grid::{Grid, KNIGHT_MOVES};
+
+fn digits(input: &str) -> Result<Grid<u32>, common::Error> {
+    Grid::parse(input, |symbol| {
+        symbol
+            .to_digit(10)
+            .ok_or_else(|| "expected a digit".to_string())
+    })
+}
+
+#[test]
+fn parses_rows_and_columns() {
+    let grid = digits("123\n456\n").unwrap();
+    assert_eq!((grid.rows(), grid.columns(), grid.len()), (2, 3, 6));
+    assert_eq!(grid.row(1), [4, 5, 6]);
+    assert_eq!(grid.column(2).copied().collect::<Vec<u32>>(), [3, 6]);
+    assert_eq!(grid[(1, 0)], 4);
+    assert_eq!(grid.find(|&cell| cell == 5), Some((1, 1)));
+    assert_eq!(grid.to_string(), "123\n456\n");
+}
+
+#[test]
+fn crlf_line_endings_are_not_cells() {
+    let lf = digits("123\n456\n").unwrap();
+    assert_eq!(digits("123\r\n456\r\n").unwrap(), lf);
+    assert_eq!(digits("123\r\r\n456\r").unwrap(), lf);
+}
+
+#[test]
+fn parse_errors_are_located() {
+    let error = digits("123\n4x6").unwrap_err();
+    let location = error.location().unwrap();
+    assert_eq!((location.line, location.column), (2, 2));
+    assert_eq!(location.token, "x");
+
+    let error = digits("123\r\n45\r\n").unwrap_err();
+    assert!(
+        error.to_string().contains("expected 3 columns, got 2"),
+        "{}",
+        error
+    );
+    assert_eq!(error.location().unwrap().line, 2);
+
+    assert!(digits(" \n ").is_err());
+}
+
+#[test]
+fn get_is_none_outside_the_grid() {
+    let mut grid = digits("12\n34").unwrap();
+    assert_eq!(grid.get((1, 1)), Some(&4));
+    assert_eq!(grid.get((2, 0)), None);
+    assert_eq!(grid.get((0, 2)), None);
+    assert_eq!(grid.get((usize::MAX, 0)), None);
+    assert_eq!(grid.get_mut((0, 2)), None);
+
+    *grid.get_mut((0, 1)).unwrap() = 9;
+    assert_eq!(grid[(0, 1)], 9);
+}
+
+#[test]
+#[should_panic(expected = "outside of a 2x2 grid")]
+fn indexing_outside_the_grid_panics() {
+    let grid = digits("12\n34").unwrap();
+    let _ = grid[(0, 2)];
+}
+
+#[test]
+fn neighbours_stay_on_the_grid() {
+    let grid = Grid::filled(3, 4, 0);
+    let sorted = |positions: Vec<(usize, usize)>| {
+        let mut positions = positions;
+        positions.sort();
+        positions
+    };
+
+    assert_eq!(sorted(grid.neighbours4((0, 0)).collect()), [(0, 1), (1, 0)]);
+    assert_eq!(sorted(grid.neighbours4((2, 3)).collect()), [(1, 3), (2, 2)]);
+    assert_eq!(grid.neighbours4((1, 1)).count(), 4);
+
+    assert_eq!(
+        sorted(grid.neighbours8((0, 3)).collect()),
+        [(0, 2), (1, 2), (1, 3)]
+    );
+    assert_eq!(grid.neighbours8((1, 2)).count(), 8);
+    assert_eq!(grid.neighbours8((2, 0)).count(), 3);
+}
+
+#[test]
+fn moves_skip_offsets_that_leave_the_grid() {
+    let grid = Grid::filled(3, 4, 0);
+    let mut corner = grid.moves((0, 0), &KNIGHT_MOVES).collect::<Vec<_>>();
+    corner.sort();
+    assert_eq!(corner, [(1, 2), (2, 1)]);
+
+    let mut edge = grid.moves((2, 3), &KNIGHT_MOVES).collect::<Vec<_>>();
+    edge.sort();
+    assert_eq!(edge, [(0, 2), (1, 1)]);
+
+    assert_eq!(grid.moves((1, 1), &[(0, 0)]).collect::<Vec<_>>(), [(1, 1)]);
+}
@@ -0,0 +1,225 @@
+use std::{
+    fmt, fs,
+    path::{Path, PathBuf},
+};
+
+use common::Error;
+use serde::{Deserialize, Serialize};
+
+use crate::inputs::InputKey;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Verdict {
+    Correct,
+    Wrong,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Wrong => write!(f, "wrong"),
+        }
+    }
+}
+
+/// One computed answer and what became of it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Entry {
+    pub answer: String,
+    #[serde(default)]
+    pub submitted: bool,
+    pub verdict: Option<Verdict>,
+}
+
+/// Every answer computed for one quest part, oldest first.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnswerLog {
+    #[serde(default, rename = "entry")]
+    pub entries: Vec<Entry>,
+}
+
+impl AnswerLog {
+    /// An empty log if the file doesn't exist yet.
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        if !path.is_file() {
+            return Ok(AnswerLog::default());
+        }
+        let content = fs::read_to_string(path).map_err(|e| Error::io(path, e))?;
+        toml::from_str(&content).map_err(|e| {
+            let token = e.span().map_or(&content[..0], |span| &content[span]);
+            Error::format(&content, token, e.message()).with_path(path)
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| Error::io(parent, e))?;
+        }
+        let content = toml::to_string(self).map_err(|e| Error::unsolvable(e.to_string()))?;
+        fs::write(path, content).map_err(|e| Error::io(path, e))
+    }
+
+    pub fn find(&self, answer: &str) -> Option<&Entry> {
+        self.entries.iter().find(|entry| entry.answer == answer)
+    }
+
+    pub fn correct(&self) -> Option<&Entry> {
+        self.entries
+            .iter()
+            .find(|entry| entry.verdict == Some(Verdict::Correct))
+    }
+
+    /// Adds `answer` unless it's already logged.
+    pub fn record(&mut self, answer: &str) -> &mut Entry {
+        let index = match self.entries.iter().position(|entry| entry.answer == answer) {
+            Some(index) => index,
+            None => {
+                self.entries.push(Entry {
+                    answer: answer.to_string(),
+                    submitted: false,
+                    verdict: None,
+                });
+                self.entries.len() - 1
+            }
+        };
+        &mut self.entries[index]
+    }
+}
+
+/// Where answers are checked.
+pub trait Submitter {
+    fn submit(&self, key: &InputKey, answer: &str) -> Result<Verdict, Error>;
+}
+
+impl Submitter for Box<dyn Submitter> {
+    fn submit(&self, key: &InputKey, answer: &str) -> Result<Verdict, Error> {
+        (**self).submit(key, answer)
+    }
+}
+
+/// Posts answers as `answer=<answer>` with the session cookie. The reply starts with the word
+/// `correct` or `wrong`, anything after it is ignored.
+#[cfg(feature = "http")]
+pub struct HttpSubmitter {
+    /// URL with placeholders, see [`crate::inputs::expand_url`].
+    pub url_template: String,
+    pub session: String,
+}
+
+#[cfg(feature = "http")]
+impl HttpSubmitter {
+    /// Reads the template from `EC_SUBMIT_URL` and the cookie from `EC_SESSION`.
+    pub fn from_env() -> Result<Self, Error> {
+        let var = |key: &str| {
+            std::env::var(key).map_err(|_| Error::param(key, "needs to be set to submit answers"))
+        };
+        Ok(HttpSubmitter {
+            url_template: var("EC_SUBMIT_URL")?,
+            session: var("EC_SESSION")?,
+        })
+    }
+}
+
+#[cfg(feature = "http")]
+impl Submitter for HttpSubmitter {
+    fn submit(&self, key: &InputKey, answer: &str) -> Result<Verdict, Error> {
+        let url = crate::inputs::expand_url(&self.url_template, key);
+        let failed = |message: String| {
+            Error::unsolvable(format!("failed to submit to {}: {}", url, message))
+        };
+        let body = ureq::post(&url)
+            .set("Cookie", &format!("everybody-codes={}", self.session))
+            .send_form(&[("answer", answer)])
+            .map_err(|e| failed(e.to_string()))?
+            .into_string()
+            .map_err(|e| failed(e.to_string()))?;
+        match body
+            .trim_start()
+            .split(|c: char| !c.is_ascii_alphabetic())
+            .next()
+        {
+            Some("correct") => Ok(Verdict::Correct),
+            Some("wrong") => Ok(Verdict::Wrong),
+            _ => Err(failed(format!("unexpected response \"{}\"", body.trim()))),
+        }
+    }
+}
+
+/// Answer logs stored next to the cached inputs, e.g. `2025/quest07/part2.answers.toml`.
+pub struct AnswerTracker<S> {
+    dir: PathBuf,
+    submitter: S,
+}
+
+impl<S> AnswerTracker<S> {
+    pub fn new(dir: impl Into<PathBuf>, submitter: S) -> Self {
+        AnswerTracker {
+            dir: dir.into(),
+            submitter,
+        }
+    }
+
+    pub fn path(&self, key: &InputKey) -> PathBuf {
+        self.dir
+            .join(key.relative_path())
+            .with_extension("answers.toml")
+    }
+
+    pub fn log(&self, key: &InputKey) -> Result<AnswerLog, Error> {
+        AnswerLog::load(&self.path(key))
+    }
+
+    /// Logs a computed answer without submitting it.
+    pub fn record(&self, key: &InputKey, answer: &str) -> Result<(), Error> {
+        let path = self.path(key);
+        let mut log = AnswerLog::load(&path)?;
+        if log.find(answer).is_none() {
+            log.record(answer);
+            log.save(&path)?;
+        }
+        Ok(())
+    }
+}
+
+impl<S: Submitter> AnswerTracker<S> {
+    /// Submits `answer` unless its verdict is already known. Resubmitting a wrong answer is an
+    /// error; so is submitting anything else once the part has been solved.
+    pub fn submit(&self, key: &InputKey, answer: &str) -> Result<Verdict, Error> {
+        let path = self.path(key);
+        let mut log = AnswerLog::load(&path)?;
+        if let Some(correct) = log.correct() {
+            if correct.answer == answer {
+                return Ok(Verdict::Correct);
+            }
+            return Err(Error::param(
+                "answer",
+                format!("\"{}\" was already accepted for this part", correct.answer),
+            ));
+        }
+        if log.find(answer).and_then(|entry| entry.verdict) == Some(Verdict::Wrong) {
+            return Err(Error::param(
+                "answer",
+                format!("\"{}\" is already known to be wrong", answer),
+            ));
+        }
+        let verdict = self.submitter.submit(key, answer)?;
+        let entry = log.record(answer);
+        entry.submitted = true;
+        entry.verdict = Some(verdict);
+        log.save(&path)?;
+        Ok(verdict)
+    }
+}
+
+/// The submitter `ec` uses: HTTP configured from the environment.
+pub fn default_submitter() -> Result<Box<dyn Submitter>, Error> {
+    #[cfg(feature = "http")]
+    return Ok(Box::new(HttpSubmitter::from_env()?));
+    #[cfg(not(feature = "http"))]
+    Err(Error::param(
+        "submit",
+        "needs ec to be built with the \"http\" feature",
+    ))
+}
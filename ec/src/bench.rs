@@ -0,0 +1,108 @@
+use std::{fs, path::Path, time::Duration};
+
+use common::{
+    Error, Part,
+    bench::{Stats, format_row},
+};
+use serde::Serialize;
+
+use crate::Quest;
+
+/// Timings of `ec bench`, written as JSON so runs on different commits can be compared.
+#[derive(Debug, Serialize)]
+pub struct Report {
+    pub results: Vec<Measurement>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Measurement {
+    pub quest: u8,
+    pub part: u8,
+    pub input: String,
+    pub answer: String,
+    pub parse: Summary,
+    pub solve: Summary,
+}
+
+/// [`Stats`] in nanoseconds.
+#[derive(Debug, Serialize)]
+pub struct Summary {
+    /// How many times the stage actually ran.
+    pub samples: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+}
+
+impl From<&Stats> for Summary {
+    fn from(stats: &Stats) -> Self {
+        let nanos = |duration: Duration| duration.as_nanos() as u64;
+        Summary {
+            samples: stats.iterations,
+            min_ns: nanos(stats.min),
+            median_ns: nanos(stats.median),
+            max_ns: nanos(stats.max),
+        }
+    }
+}
+
+impl Report {
+    /// Benches every part in `parts` of every quest in `quests`, on the input `input_for` returns.
+    pub fn run(
+        quests: &[&Quest],
+        parts: &[Part],
+        mut input_for: impl FnMut(&Quest, Part) -> Result<String, Error>,
+        params: &[(String, String)],
+        iterations: usize,
+    ) -> Result<Self, Error> {
+        let mut results = Vec::new();
+        for quest in quests {
+            for &part in parts {
+                let input = input_for(quest, part)?;
+                let timing = (quest.bench)(&input, part, params, iterations)?;
+                results.push(Measurement {
+                    quest: quest.number,
+                    part: part.number(),
+                    input,
+                    answer: timing.answer,
+                    parse: Summary::from(&timing.parse),
+                    solve: Summary::from(&timing.solve),
+                });
+            }
+        }
+        Ok(Report { results })
+    }
+
+    pub fn table(&self) -> String {
+        let mut lines = Vec::new();
+        for result in &self.results {
+            lines.push(format!(
+                "quest {} part {}: {}",
+                result.quest, result.part, result.answer
+            ));
+            for (stage, summary) in [("parse", &result.parse), ("solve", &result.solve)] {
+                lines.push(format!("  {}", format_row(stage, &summary.stats())));
+            }
+        }
+        lines.join("\n")
+    }
+
+    pub fn write_json(&self, path: &Path) -> Result<(), Error> {
+        let json =
+            serde_json::to_string_pretty(self).map_err(|e| Error::unsolvable(e.to_string()))?;
+        fs::write(path, json + "\n").map_err(|e| Error::io(path, e))
+    }
+}
+
+impl Summary {
+    /// Back to [`Stats`] for printing.
+    fn stats(&self) -> Stats {
+        let duration = Duration::from_nanos;
+        Stats {
+            iterations: self.samples,
+            min: duration(self.min_ns),
+            median: duration(self.median_ns),
+            max: duration(self.max_ns),
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use common::{Error, Part};
+
+/// Everybody Codes event the quests in this workspace belong to.
+pub const DEFAULT_EVENT: u16 = 2025;
+
+/// Identifies one puzzle input: every part of every quest has its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InputKey {
+    pub event: u16,
+    pub quest: u8,
+    pub part: Part,
+}
+
+impl InputKey {
+    pub fn new(quest: u8, part: Part) -> Self {
+        InputKey {
+            event: DEFAULT_EVENT,
+            quest,
+            part,
+        }
+    }
+
+    /// `2025/quest07/part2.txt`, the layout of both the cache and [`FixtureDir`].
+    pub fn relative_path(&self) -> PathBuf {
+        Path::new(&self.event.to_string())
+            .join(format!("quest{:02}", self.quest))
+            .join(format!("part{}.txt", self.part))
+    }
+}
+
+/// Where missing inputs come from.
+pub trait Transport {
+    fn fetch(&self, key: &InputKey) -> Result<String, Error>;
+}
+
+/// Serves inputs from a directory laid out like the cache, e.g. a shared folder or test fixtures.
+pub struct FixtureDir {
+    pub root: PathBuf,
+}
+
+impl Transport for FixtureDir {
+    fn fetch(&self, key: &InputKey) -> Result<String, Error> {
+        let path = self.root.join(key.relative_path());
+        fs::read_to_string(&path).map_err(|e| Error::io(&path, e))
+    }
+}
+
+/// Downloads inputs over HTTP(S) with the logged-in session cookie.
+#[cfg(feature = "http")]
+pub struct HttpTransport {
+    /// URL with placeholders, see [`expand_url`].
+    pub url_template: String,
+    pub session: String,
+}
+
+#[cfg(feature = "http")]
+impl HttpTransport {
+    /// Reads the template from `EC_INPUT_URL` and the cookie from `EC_SESSION`.
+    pub fn from_env() -> Result<Self, Error> {
+        let var = |key: &str| {
+            env::var(key).map_err(|_| Error::param(key, "needs to be set to download inputs"))
+        };
+        Ok(HttpTransport {
+            url_template: var("EC_INPUT_URL")?,
+            session: var("EC_SESSION")?,
+        })
+    }
+
+    pub fn url(&self, key: &InputKey) -> String {
+        expand_url(&self.url_template, key)
+    }
+}
+
+/// Fills the `{event}`, `{quest}` and `{part}` placeholders of `template`.
+pub fn expand_url(template: &str, key: &InputKey) -> String {
+    template
+        .replace("{event}", &key.event.to_string())
+        .replace("{quest}", &key.quest.to_string())
+        .replace("{part}", &key.part.to_string())
+}
+
+#[cfg(feature = "http")]
+impl Transport for HttpTransport {
+    fn fetch(&self, key: &InputKey) -> Result<String, Error> {
+        let url = self.url(key);
+        let failed =
+            |message: String| Error::unsolvable(format!("failed to fetch {}: {}", url, message));
+        ureq::get(&url)
+            .set("Cookie", &format!("everybody-codes={}", self.session))
+            .call()
+            .map_err(|e| failed(e.to_string()))?
+            .into_string()
+            .map_err(|e| failed(e.to_string()))
+    }
+}
+
+/// Inputs stored under `dir` by [`InputKey::relative_path`]. A cached input is never fetched
+/// again; delete its file to force a new download.
+pub struct InputCache<T> {
+    dir: PathBuf,
+    transport: T,
+}
+
+impl<T: Transport> InputCache<T> {
+    pub fn new(dir: impl Into<PathBuf>, transport: T) -> Self {
+        InputCache {
+            dir: dir.into(),
+            transport,
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn path(&self, key: &InputKey) -> PathBuf {
+        self.dir.join(key.relative_path())
+    }
+
+    pub fn is_cached(&self, key: &InputKey) -> bool {
+        self.path(key).is_file()
+    }
+
+    /// Path of the cached input, fetching it first if needed.
+    pub fn get(&self, key: &InputKey) -> Result<PathBuf, Error> {
+        let path = self.path(key);
+        if path.is_file() {
+            return Ok(path);
+        }
+        let text = self.transport.fetch(key)?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| Error::io(parent, e))?;
+        }
+        // Written next to the target and renamed, so an interrupted fetch never looks cached.
+        let partial = path.with_extension("partial");
+        fs::write(&partial, text).map_err(|e| Error::io(&partial, e))?;
+        fs::rename(&partial, &path).map_err(|e| Error::io(&path, e))?;
+        Ok(path)
+    }
+}
+
+/// `EC_CACHE_DIR`, falling back to `$XDG_CACHE_HOME/everybody-codes` and then
+/// `~/.cache/everybody-codes`.
+pub fn default_cache_dir() -> Result<PathBuf, Error> {
+    if let Some(dir) = env::var_os("EC_CACHE_DIR") {
+        return Ok(PathBuf::from(dir));
+    }
+    if let Some(dir) = env::var_os("XDG_CACHE_HOME") {
+        return Ok(PathBuf::from(dir).join("everybody-codes"));
+    }
+    env::var_os("HOME")
+        .map(|home| PathBuf::from(home).join(".cache").join("everybody-codes"))
+        .ok_or_else(|| Error::param("EC_CACHE_DIR", "not set and there is no home directory"))
+}
+
+/// The transport `ec` uses: `EC_FIXTURE_DIR` if set, otherwise HTTP configured from the
+/// environment.
+pub fn default_transport() -> Result<Box<dyn Transport>, Error> {
+    if let Some(root) = env::var_os("EC_FIXTURE_DIR") {
+        return Ok(Box::new(FixtureDir {
+            root: PathBuf::from(root),
+        }));
+    }
+    #[cfg(feature = "http")]
+    return Ok(Box::new(HttpTransport::from_env()?));
+    #[cfg(not(feature = "http"))]
+    Err(Error::param(
+        "EC_FIXTURE_DIR",
+        "needs to be set when ec is built without the \"http\" feature",
+    ))
+}
+
+impl Transport for Box<dyn Transport> {
+    fn fetch(&self, key: &InputKey) -> Result<String, Error> {
+        (**self).fetch(key)
+    }
+}
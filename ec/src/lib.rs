@@ -0,0 +1,99 @@
+pub mod answers;
+pub mod bench;
+pub mod inputs;
+pub mod manifest;
+pub mod scaffold;
+
+use std::path::Path;
+
+use common::{
+    Error, Part,
+    bench::{PartTiming, bench_part},
+    solve,
+};
+
+pub type SolveFn = fn(&str, Part, &[(String, String)]) -> Result<String, Error>;
+
+/// Like [`SolveFn`], but also writes a trace in the given format to the path or stdout.
+pub type TraceFn =
+    fn(&str, Part, &[(String, String)], &str, Option<&Path>) -> Result<String, Error>;
+
+/// Times parsing and solving the input at the path over the given number of iterations.
+pub type BenchFn = fn(&str, Part, &[(String, String)], usize) -> Result<PartTiming, Error>;
+
+pub struct Quest {
+    pub number: u8,
+    pub solve: SolveFn,
+    pub trace: Option<TraceFn>,
+    pub bench: BenchFn,
+}
+
+pub const QUESTS: &[Quest] = &[
+    Quest {
+        number: 1,
+        solve: solve::<quest1::Quest1>,
+        trace: Some(quest1::trace::trace),
+        bench: bench_part::<quest1::Quest1>,
+    },
+    Quest {
+        number: 2,
+        solve: solve::<quest2::Quest2>,
+        trace: Some(quest2::render::trace),
+        bench: bench_part::<quest2::Quest2>,
+    },
+    Quest {
+        number: 3,
+        solve: solve::<quest3::Quest3>,
+        trace: None,
+        bench: bench_part::<quest3::Quest3>,
+    },
+    Quest {
+        number: 4,
+        solve: solve::<quest4::Quest4>,
+        trace: None,
+        bench: bench_part::<quest4::Quest4>,
+    },
+    Quest {
+        number: 5,
+        solve: solve::<quest5::Quest5>,
+        trace: None,
+        bench: bench_part::<quest5::Quest5>,
+    },
+    Quest {
+        number: 6,
+        solve: solve::<quest6::Quest6>,
+        trace: None,
+        bench: bench_part::<quest6::Quest6>,
+    },
+    Quest {
+        number: 7,
+        solve: solve::<quest7::Quest7>,
+        trace: None,
+        bench: bench_part::<quest7::Quest7>,
+    },
+    Quest {
+        number: 8,
+        solve: solve::<quest8::Quest8>,
+        trace: None,
+        bench: bench_part::<quest8::Quest8>,
+    },
+    Quest {
+        number: 9,
+        solve: solve::<quest9::Quest9>,
+        trace: None,
+        bench: bench_part::<quest9::Quest9>,
+    },
+    Quest {
+        number: 10,
+        solve: solve::<quest10::Quest10>,
+        trace: Some(quest10::trace::trace),
+        bench: bench_part::<quest10::Quest10>,
+    },
+];
+
+pub fn find_quest(number: u8) -> Result<&'static Quest, Error> {
+    QUESTS
+        .iter()
+        .find(|quest| quest.number == number)
+        .ok_or_else(|| Error::param("quest", format!("no solution for quest {}", number)))
+}
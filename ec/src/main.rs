@@ -0,0 +1,279 @@
+use std::{env, path::PathBuf, process::ExitCode};
+
+use common::{Error, Part, parse_param};
+use ec::answers::{AnswerTracker, default_submitter};
+use ec::bench::Report;
+use ec::inputs::DEFAULT_EVENT;
+use ec::inputs::{InputCache, InputKey, default_cache_dir, default_transport};
+use ec::scaffold;
+use ec::{QUESTS, Quest, find_quest};
+
+const USAGE: &str = "Usage:
+  ec run --quest <N> --part <1|2|3> [--input <PATH|->] [--event <YEAR>] [--param <KEY=VALUE>]... \
+[--trace <FORMAT> [--trace-out <PATH>]]
+  ec fetch --quest <N> --part <1|2|3> [--event <YEAR>]
+  ec submit --quest <N> --part <1|2|3> [--event <YEAR>] [--param <KEY=VALUE>]...
+  ec answers --quest <N> --part <1|2|3> [--event <YEAR>]
+  ec bench [--quest <N>] [--part <1|2|3>] [--input <PATH>] [--event <YEAR>] [--param <KEY=VALUE>]... \
+[--iterations <K>] [--json <PATH>]
+  ec new --quest <N> [--root <PATH>]
+Without --input the input is taken from the cache in EC_CACHE_DIR, downloading it first if needed,
+and the answer is added to the part's answer log.";
+
+/// Default `--iterations` of `ec bench`.
+const BENCH_ITERATIONS: usize = 10;
+
+struct RunArgs {
+    quest: u8,
+    part: Part,
+    input: Option<String>,
+    event: u16,
+    params: Vec<(String, String)>,
+    trace: Option<String>,
+    trace_out: Option<PathBuf>,
+}
+
+/// Like [`RunArgs`], but without a quest or part every one is benched.
+struct BenchArgs {
+    quest: Option<u8>,
+    part: Option<Part>,
+    input: Option<String>,
+    event: u16,
+    params: Vec<(String, String)>,
+    iterations: usize,
+    json: Option<PathBuf>,
+}
+
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<String>>();
+    let result = match args.first().map(String::as_str) {
+        Some("run") => parse_run_args(&args[1..]).and_then(|run_args| run(&run_args)),
+        Some("fetch") => parse_run_args(&args[1..])
+            .and_then(|run_args| cached_input(&run_args).map(|path| path.display().to_string())),
+        Some("submit") => parse_run_args(&args[1..]).and_then(|run_args| submit(&run_args)),
+        Some("answers") => parse_run_args(&args[1..]).and_then(|run_args| answers(&run_args)),
+        Some("bench") => parse_bench_args(&args[1..]).and_then(|bench_args| bench(&bench_args)),
+        Some("new") => new_quest(&args[1..]),
+        _ => Err(Error::param("command", USAGE)),
+    };
+    match result {
+        Ok(answer) => {
+            println!("{}", answer);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{}", e.diagnostic());
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn parse_run_args(args: &[String]) -> Result<RunArgs, Error> {
+    let mut quest = None;
+    let mut part = None;
+    let mut input = None;
+    let mut event = DEFAULT_EVENT;
+    let mut params = Vec::new();
+    let mut trace = None;
+    let mut trace_out = None;
+
+    let mut args = args.iter();
+    while let Some(flag) = args.next() {
+        let value = args
+            .next()
+            .ok_or_else(|| Error::param(flag, "missing value"))?;
+        match flag.as_str() {
+            "--quest" | "-q" => quest = Some(parse_param::<u8>("quest", value)?),
+            "--part" | "-p" => part = Some(value.parse::<Part>()?),
+            "--input" | "-i" => input = Some(value.clone()),
+            "--event" => event = parse_param("event", value)?,
+            "--param" => {
+                let (key, value) = value.split_once('=').ok_or_else(|| {
+                    Error::param(flag, format!("expected KEY=VALUE, got \"{}\"", value))
+                })?;
+                params.push((key.to_string(), value.to_string()));
+            }
+            "--trace" => trace = Some(value.clone()),
+            "--trace-out" => trace_out = Some(PathBuf::from(value)),
+            _ => return Err(Error::param(flag, format!("unknown argument\n{}", USAGE))),
+        }
+    }
+
+    Ok(RunArgs {
+        quest: quest.ok_or_else(|| Error::param("--quest", format!("missing\n{}", USAGE)))?,
+        part: part.ok_or_else(|| Error::param("--part", format!("missing\n{}", USAGE)))?,
+        input,
+        event,
+        params,
+        trace,
+        trace_out,
+    })
+}
+
+fn parse_bench_args(args: &[String]) -> Result<BenchArgs, Error> {
+    let mut bench_args = BenchArgs {
+        quest: None,
+        part: None,
+        input: None,
+        event: DEFAULT_EVENT,
+        params: Vec::new(),
+        iterations: BENCH_ITERATIONS,
+        json: None,
+    };
+
+    let mut args = args.iter();
+    while let Some(flag) = args.next() {
+        let value = args
+            .next()
+            .ok_or_else(|| Error::param(flag, "missing value"))?;
+        match flag.as_str() {
+            "--quest" | "-q" => bench_args.quest = Some(parse_param::<u8>("quest", value)?),
+            "--part" | "-p" => bench_args.part = Some(value.parse::<Part>()?),
+            "--input" | "-i" => bench_args.input = Some(value.clone()),
+            "--event" => bench_args.event = parse_param("event", value)?,
+            "--param" => {
+                let (key, value) = value.split_once('=').ok_or_else(|| {
+                    Error::param(flag, format!("expected KEY=VALUE, got \"{}\"", value))
+                })?;
+                bench_args.params.push((key.to_string(), value.to_string()));
+            }
+            "--iterations" => {
+                bench_args.iterations = parse_param("iterations", value)?;
+                if bench_args.iterations == 0 {
+                    return Err(Error::param(flag, "expected at least 1"));
+                }
+            }
+            "--json" => bench_args.json = Some(PathBuf::from(value)),
+            _ => return Err(Error::param(flag, format!("unknown argument\n{}", USAGE))),
+        }
+    }
+
+    if bench_args.input.is_some() && (bench_args.quest.is_none() || bench_args.part.is_none()) {
+        return Err(Error::param(
+            "--input",
+            "needs a single --quest and --part to bench",
+        ));
+    }
+    Ok(bench_args)
+}
+
+fn input_key(args: &RunArgs) -> InputKey {
+    InputKey {
+        event: args.event,
+        quest: args.quest,
+        part: args.part,
+    }
+}
+
+fn cached_input(args: &RunArgs) -> Result<PathBuf, Error> {
+    let cache = InputCache::new(default_cache_dir()?, default_transport()?);
+    cache.get(&input_key(args))
+}
+
+fn run(args: &RunArgs) -> Result<String, Error> {
+    let quest = find_quest(args.quest)?;
+    let input = match &args.input {
+        Some(input) => input.clone(),
+        None => cached_input(args)?.display().to_string(),
+    };
+    match (&args.trace, quest.trace) {
+        (None, _) => {
+            let answer = (quest.solve)(&input, args.part, &args.params)?;
+            // Only answers for the real input belong in the log, not those for examples.
+            if args.input.is_none() {
+                AnswerTracker::new(default_cache_dir()?, ()).record(&input_key(args), &answer)?;
+            }
+            Ok(answer)
+        }
+        (Some(format), Some(trace)) => trace(
+            &input,
+            args.part,
+            &args.params,
+            format,
+            args.trace_out.as_deref(),
+        ),
+        (Some(_), None) => Err(Error::param(
+            "--trace",
+            format!("quest {} has no trace mode", args.quest),
+        )),
+    }
+}
+
+fn submit(args: &RunArgs) -> Result<String, Error> {
+    if args.input.is_some() || args.trace.is_some() {
+        return Err(Error::param(
+            "submit",
+            "answers are always computed from the cached input, without tracing",
+        ));
+    }
+    let answer = run(args)?;
+    let tracker = AnswerTracker::new(default_cache_dir()?, default_submitter()?);
+    let verdict = tracker.submit(&input_key(args), &answer)?;
+    Ok(format!("{}: {}", answer, verdict))
+}
+
+fn answers(args: &RunArgs) -> Result<String, Error> {
+    let log = AnswerTracker::new(default_cache_dir()?, ()).log(&input_key(args))?;
+    Ok(log
+        .entries
+        .iter()
+        .map(|entry| match (entry.verdict, entry.submitted) {
+            (Some(verdict), _) => format!("{}: {}", entry.answer, verdict),
+            (None, true) => format!("{}: submitted", entry.answer),
+            (None, false) => format!("{}: not submitted", entry.answer),
+        })
+        .collect::<Vec<String>>()
+        .join("\n"))
+}
+
+fn bench(args: &BenchArgs) -> Result<String, Error> {
+    let quests = match args.quest {
+        Some(number) => vec![find_quest(number)?],
+        None => QUESTS.iter().collect(),
+    };
+    let parts = args.part.map_or(Part::ALL.to_vec(), |part| vec![part]);
+    let input_for = |quest: &Quest, part: Part| match &args.input {
+        Some(input) => Ok(input.clone()),
+        None => {
+            let cache = InputCache::new(default_cache_dir()?, default_transport()?);
+            let key = InputKey {
+                event: args.event,
+                quest: quest.number,
+                part,
+            };
+            Ok(cache.get(&key)?.display().to_string())
+        }
+    };
+    let report = Report::run(&quests, &parts, input_for, &args.params, args.iterations)?;
+    if let Some(path) = &args.json {
+        report.write_json(path)?;
+    }
+    Ok(report.table())
+}
+
+fn new_quest(args: &[String]) -> Result<String, Error> {
+    let mut quest = None;
+    let mut root = None;
+    let mut args = args.iter();
+    while let Some(flag) = args.next() {
+        let value = args
+            .next()
+            .ok_or_else(|| Error::param(flag, "missing value"))?;
+        match flag.as_str() {
+            "--quest" | "-q" => quest = Some(parse_param::<u8>("quest", value)?),
+            "--root" => root = Some(PathBuf::from(value)),
+            _ => return Err(Error::param(flag, format!("unknown argument\n{}", USAGE))),
+        }
+    }
+    let quest = quest.ok_or_else(|| Error::param("--quest", format!("missing\n{}", USAGE)))?;
+    let root = match root {
+        Some(root) => root,
+        None => scaffold::current_workspace_root()?,
+    };
+    let written = scaffold::new_quest(&root, quest)?;
+    Ok(written
+        .iter()
+        .map(|path| format!("wrote {}", path.display()))
+        .collect::<Vec<String>>()
+        .join("\n"))
+}
@@ -0,0 +1,99 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use common::{Error, Part};
+use serde::Deserialize;
+
+use crate::Quest;
+
+pub const MANIFEST_FILE_NAME: &str = "answers.toml";
+
+/// Expected answers of one quest, read from `questN/answers.toml`.
+#[derive(Debug, Deserialize)]
+pub struct Manifest {
+    #[serde(default, rename = "case")]
+    pub cases: Vec<Case>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Case {
+    /// Path of the input file, relative to the quest crate directory.
+    pub input: String,
+    pub part: u8,
+    #[serde(default)]
+    pub params: BTreeMap<String, toml::Value>,
+    pub expected: toml::Value,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    Passed,
+    Failed {
+        expected: String,
+        actual: String,
+    },
+    Errored(String),
+    /// The input file isn't there, which is normal for the untracked `input/` folders.
+    Skipped,
+}
+
+impl Manifest {
+    pub fn path(quest_dir: &Path) -> PathBuf {
+        quest_dir.join(MANIFEST_FILE_NAME)
+    }
+
+    pub fn load(quest_dir: &Path) -> Result<Self, Error> {
+        let path = Manifest::path(quest_dir);
+        let content = fs::read_to_string(&path).map_err(|e| Error::io(&path, e))?;
+        toml::from_str(&content).map_err(|e| {
+            let token = e.span().map_or(&content[..0], |span| &content[span]);
+            Error::format(&content, token, e.message()).with_path(&path)
+        })
+    }
+}
+
+impl Case {
+    pub fn part(&self) -> Result<Part, Error> {
+        Part::try_from(self.part)
+    }
+
+    pub fn params(&self) -> Vec<(String, String)> {
+        self.params
+            .iter()
+            .map(|(key, value)| (key.clone(), value_to_string(value)))
+            .collect()
+    }
+
+    pub fn expected(&self) -> String {
+        value_to_string(&self.expected)
+    }
+
+    pub fn check(&self, quest: &Quest, quest_dir: &Path) -> Outcome {
+        let input_path = quest_dir.join(&self.input);
+        if !input_path.exists() {
+            return Outcome::Skipped;
+        }
+        let part = match self.part() {
+            Ok(part) => part,
+            Err(e) => return Outcome::Errored(e.to_string()),
+        };
+        match (quest.solve)(&input_path.to_string_lossy(), part, &self.params()) {
+            Ok(actual) if actual == self.expected() => Outcome::Passed,
+            Ok(actual) => Outcome::Failed {
+                expected: self.expected(),
+                actual,
+            },
+            Err(e) => Outcome::Errored(e.to_string()),
+        }
+    }
+}
+
+fn value_to_string(value: &toml::Value) -> String {
+    match value {
+        toml::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
@@ -0,0 +1,273 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use common::Error;
+
+const CARGO_TOML: &str = r#"[package]
+name = "quest{n}"
+version = "0.1.0"
+edition = "2024"
+
+[dependencies]
+common.workspace = true
+
+[[bench]]
+name = "parts"
+harness = false
+"#;
+
+const GITIGNORE: &str = "/input
+/target
+";
+
+const LIB_RS: &str = r#"use common::{Error, Input, Solution};
+
+pub fn extract_input_from_file(file_path: &str) -> Result<Vec<String>, Error> {
+    parse_input(&Input::open(file_path)?)
+}
+
+pub fn parse_input(input: &Input) -> Result<Vec<String>, Error> {
+    Ok(input.lines().map(str::to_string).collect())
+}
+
+pub fn part1(_input: &[String]) -> Result<usize, Error> {
+    Err(Error::unsolvable("part 1 isn't solved yet"))
+}
+
+pub fn part2(_input: &[String]) -> Result<usize, Error> {
+    Err(Error::unsolvable("part 2 isn't solved yet"))
+}
+
+pub fn part3(_input: &[String]) -> Result<usize, Error> {
+    Err(Error::unsolvable("part 3 isn't solved yet"))
+}
+
+pub struct Quest{n};
+
+impl Solution for Quest{n} {
+    type Input = Vec<String>;
+    type Params = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Answer3 = usize;
+
+    fn parse_input(input: &Input) -> Result<Self::Input, Error> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Vec<String>, _: &()) -> Result<usize, Error> {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<String>, _: &()) -> Result<usize, Error> {
+        part2(input)
+    }
+
+    fn part3(input: &Vec<String>, _: &()) -> Result<usize, Error> {
+        part3(input)
+    }
+}
+"#;
+
+const MAIN_RS: &str = r#"use common::Part;
+
+fn main() {
+    if common::run_from_args::<quest{n}::Quest{n}>() {
+        return;
+    }
+    for part in Part::ALL {
+        for (label, path) in [
+            ("Test", format!("tests/input/test{}.txt", part)),
+            ("Part", format!("input/input{}.txt", part)),
+        ] {
+            match common::solve::<quest{n}::Quest{n}>(&path, part, &[]) {
+                Ok(answer) => println!("{} {}: {}", label, part, answer),
+                Err(e) => println!("Error: {}", e),
+            }
+        }
+    }
+}
+"#;
+
+const BENCH_RS: &str = r#"//! Parse and part timings on the real inputs and the examples: `cargo bench -p quest{n} --bench parts`.
+
+use common::Part;
+
+const ITERATIONS: usize = 10;
+
+fn main() {
+    common::bench::main::<quest{n}::Quest{n}>(
+        &[
+            (Part::One, "input/input1.txt", &[]),
+            (Part::Two, "input/input2.txt", &[]),
+            (Part::Three, "input/input3.txt", &[]),
+            (Part::One, "tests/input/test1.txt", &[]),
+        ],
+        ITERATIONS,
+    );
+}
+"#;
+
+/// Fails until the example and its answer are filled in and part 1 is solved.
+const ANSWERS_TOML: &str = r#"# Expected answers checked by `cargo test -p ec`.
+# Cases whose input file is missing (e.g. the untracked input/ folder) are skipped.
+
+# TODO: paste the example into tests/input/test1.txt and its answer below.
+[[case]]
+input = "tests/input/test1.txt"
+part = 1
+expected = 0
+"#;
+
+/// The nearest directory at or above `start` whose `Cargo.toml` has a `[workspace]`.
+pub fn find_workspace_root(start: &Path) -> Result<PathBuf, Error> {
+    start
+        .ancestors()
+        .find(|dir| {
+            fs::read_to_string(dir.join("Cargo.toml"))
+                .is_ok_and(|manifest| manifest.lines().any(|line| line.trim() == "[workspace]"))
+        })
+        .map(Path::to_path_buf)
+        .ok_or_else(|| Error::param("root", format!("no workspace above {}", start.display())))
+}
+
+pub fn current_workspace_root() -> Result<PathBuf, Error> {
+    let dir = env::current_dir().map_err(|e| Error::io(".", e))?;
+    find_workspace_root(&dir)
+}
+
+/// Creates the `questN` crate under `root` and registers it with the workspace, the `ec`
+/// dependencies and [`crate::QUESTS`]. Returns every file written, in order.
+pub fn new_quest(root: &Path, number: u8) -> Result<Vec<PathBuf>, Error> {
+    let name = format!("quest{}", number);
+    let quest_dir = root.join(&name);
+    if quest_dir.exists() {
+        return Err(Error::param(
+            "quest",
+            format!("{} already exists", quest_dir.display()),
+        ));
+    }
+
+    // Check every edit applies before touching anything, so a failure leaves no half-made quest.
+    let workspace_manifest = root.join("Cargo.toml");
+    let ec_manifest = root.join("ec").join("Cargo.toml");
+    let ec_lib = root.join("ec").join("src").join("lib.rs");
+    let edits = [
+        (
+            &workspace_manifest,
+            register_workspace_member(&read(&workspace_manifest)?, &name)?,
+        ),
+        (
+            &ec_manifest,
+            register_ec_dependency(&read(&ec_manifest)?, &name)?,
+        ),
+        (&ec_lib, register_quest(&read(&ec_lib)?, number)?),
+    ];
+
+    let n = number.to_string();
+    let files = [
+        ("Cargo.toml", CARGO_TOML),
+        (".gitignore", GITIGNORE),
+        ("src/lib.rs", LIB_RS),
+        ("src/main.rs", MAIN_RS),
+        ("benches/parts.rs", BENCH_RS),
+        ("answers.toml", ANSWERS_TOML),
+        ("tests/input/test1.txt", ""),
+    ];
+    let mut written = Vec::new();
+    for (file, template) in files {
+        let path = quest_dir.join(file);
+        write(&path, &template.replace("{n}", &n))?;
+        written.push(path);
+    }
+    let input_dir = quest_dir.join("input");
+    fs::create_dir_all(&input_dir).map_err(|e| Error::io(&input_dir, e))?;
+
+    for (path, content) in edits {
+        write(path, &content)?;
+        written.push(path.clone());
+    }
+    Ok(written)
+}
+
+fn read(path: &Path) -> Result<String, Error> {
+    fs::read_to_string(path).map_err(|e| Error::io(path, e))
+}
+
+fn write(path: &Path, content: &str) -> Result<(), Error> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| Error::io(parent, e))?;
+    }
+    fs::write(path, content).map_err(|e| Error::io(path, e))
+}
+
+/// `text` with `line` inserted after the last line matching `anchor`.
+fn insert_line(text: &str, line: &str, anchor: impl Fn(&str) -> bool) -> Option<String> {
+    let lines = text.lines().collect::<Vec<&str>>();
+    let index = lines.iter().rposition(|line| anchor(line))?;
+    let mut result = lines[..=index].join("\n");
+    result.push('\n');
+    result.push_str(line);
+    for rest in &lines[index + 1..] {
+        result.push('\n');
+        result.push_str(rest);
+    }
+    result.push('\n');
+    Some(result)
+}
+
+fn is_quest_line(line: &str, prefix: &str, suffix: &str) -> bool {
+    line.trim()
+        .strip_prefix(prefix)
+        .and_then(|rest| rest.split_once(suffix))
+        .is_some_and(|(number, _)| !number.is_empty() && number.bytes().all(|b| b.is_ascii_digit()))
+}
+
+fn register_workspace_member(manifest: &str, name: &str) -> Result<String, Error> {
+    if manifest.contains(&format!("\"{}\"", name)) {
+        return Err(Error::param(
+            "quest",
+            format!("{} is already a workspace member", name),
+        ));
+    }
+    let missing = || Error::param("root", "the workspace Cargo.toml doesn't list quest crates");
+    let manifest = insert_line(manifest, &format!("    \"{}\",", name), |line| {
+        is_quest_line(line, "\"quest", "\",")
+    })
+    .ok_or_else(missing)?;
+    insert_line(
+        &manifest,
+        &format!("{} = {{ path = \"{}\" }}", name, name),
+        |line| is_quest_line(line, "quest", " = { path"),
+    )
+    .ok_or_else(missing)
+}
+
+fn register_ec_dependency(manifest: &str, name: &str) -> Result<String, Error> {
+    insert_line(manifest, &format!("{}.workspace = true", name), |line| {
+        is_quest_line(line, "quest", ".workspace")
+    })
+    .ok_or_else(|| Error::param("root", "ec/Cargo.toml doesn't depend on any quest crate"))
+}
+
+fn register_quest(lib: &str, number: u8) -> Result<String, Error> {
+    let entry = format!(
+        "    Quest {{
+        number: {n},
+        solve: solve::<quest{n}::Quest{n}>,
+        trace: None,
+        bench: bench_part::<quest{n}::Quest{n}>,
+    }},",
+        n = number
+    );
+    let start = lib
+        .find("pub const QUESTS")
+        .ok_or_else(|| Error::param("root", "ec/src/lib.rs has no QUESTS registry"))?;
+    let end = start
+        + lib[start..]
+            .find("\n];")
+            .ok_or_else(|| Error::param("root", "ec/src/lib.rs has no QUESTS registry"))?;
+    Ok(format!("{}\n{}{}", &lib[..end], entry, &lib[end..]))
+}
@@ -0,0 +1,64 @@
+use std::path::Path;
+
+use common::Error;
+use ec::{
+    QUESTS,
+    manifest::{Manifest, Outcome},
+};
+
+#[test]
+fn answers_match_manifests() {
+    let workspace_root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let mut failures = Vec::new();
+
+    for quest in QUESTS {
+        let quest_dir = workspace_root.join(format!("quest{}", quest.number));
+        let manifest = Manifest::load(&quest_dir).unwrap_or_else(|e| panic!("{}", e.diagnostic()));
+        for case in &manifest.cases {
+            let label = format!("quest{} part {} ({})", quest.number, case.part, case.input);
+            match case.check(quest, &quest_dir) {
+                Outcome::Passed => println!("{} ... ok", label),
+                Outcome::Skipped => println!("{} ... skipped, input missing", label),
+                Outcome::Failed { expected, actual } => {
+                    println!("{} ... FAILED", label);
+                    failures.push(format!(
+                        "{}\n  - expected: {}\n  + actual:   {}",
+                        label, expected, actual
+                    ));
+                }
+                Outcome::Errored(e) => {
+                    println!("{} ... ERROR", label);
+                    failures.push(format!("{}\n  error: {}", label, e));
+                }
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} case(s) failed:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn malformed_manifests_are_located() {
+    let quest_dir = std::env::temp_dir().join(format!("ec-manifest-{}", std::process::id()));
+    std::fs::create_dir_all(&quest_dir).unwrap();
+    let path = Manifest::path(&quest_dir);
+    std::fs::write(
+        &path,
+        "[[case]]\ninput = \"tests/input/test1.txt\"\npart = \"one\"\n",
+    )
+    .unwrap();
+    let error = Manifest::load(&quest_dir).unwrap_err();
+    std::fs::remove_dir_all(&quest_dir).unwrap();
+
+    let location = error.location().expect("located error");
+    assert_eq!((location.line, location.token.as_str()), (3, "\"one\""));
+    assert_eq!(location.path.as_deref(), Some(path.as_path()));
+
+    let missing = Manifest::load(&quest_dir).unwrap_err();
+    assert!(matches!(missing, Error::Io { .. }), "{:?}", missing);
+}
@@ -0,0 +1,47 @@
+use common::Part;
+use ec::{bench::Report, find_quest};
+
+fn report(iterations: usize) -> Report {
+    let quest = find_quest(1).unwrap();
+    let input = format!(
+        "{}/../quest1/tests/input/test1.txt",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    Report::run(
+        &[quest],
+        &[Part::One, Part::Two],
+        |_, _| Ok(input.clone()),
+        &[],
+        iterations,
+    )
+    .unwrap()
+}
+
+#[test]
+fn summaries_record_the_samples_taken() {
+    for (iterations, samples) in [(0, 1), (1, 1), (3, 3)] {
+        let report = report(iterations);
+        assert_eq!(report.results.len(), 2);
+        for result in &report.results {
+            assert_eq!(result.parse.samples, samples, "{} iterations", iterations);
+            assert_eq!(result.solve.samples, samples, "{} iterations", iterations);
+            assert!(result.solve.min_ns <= result.solve.median_ns);
+            assert!(result.solve.median_ns <= result.solve.max_ns);
+        }
+    }
+}
+
+#[test]
+fn json_reports_samples_next_to_the_timings() {
+    let path = std::env::temp_dir().join(format!("ec-bench-{}.json", std::process::id()));
+    report(0).write_json(&path).unwrap();
+    let json = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let value = serde_json::from_str::<serde_json::Value>(&json).unwrap();
+    assert_eq!(value.get("iterations"), None);
+    for result in value["results"].as_array().unwrap() {
+        assert_eq!(result["parse"]["samples"], 1);
+        assert_eq!(result["solve"]["samples"], 1);
+    }
+}
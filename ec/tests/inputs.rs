@@ -0,0 +1,133 @@
+use std::{cell::Cell, fs, path::PathBuf, rc::Rc};
+
+use common::{Error, Part};
+use ec::inputs::{FixtureDir, InputCache, InputKey, Transport};
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("ec-inputs-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+struct Counting {
+    fetches: Rc<Cell<usize>>,
+}
+
+impl Transport for Counting {
+    fn fetch(&self, key: &InputKey) -> Result<String, Error> {
+        self.fetches.set(self.fetches.get() + 1);
+        Ok(format!("quest {} part {}\n", key.quest, key.part))
+    }
+}
+
+#[test]
+fn cached_inputs_are_not_fetched_again() {
+    let dir = temp_dir("counting");
+    let fetches = Rc::new(Cell::new(0));
+    let cache = InputCache::new(
+        &dir,
+        Counting {
+            fetches: fetches.clone(),
+        },
+    );
+    let key = InputKey::new(7, Part::Two);
+
+    assert!(!cache.is_cached(&key));
+    let path = cache.get(&key).unwrap();
+    assert_eq!(path, dir.join("2025").join("quest07").join("part2.txt"));
+    assert_eq!(fs::read_to_string(&path).unwrap(), "quest 7 part 2\n");
+    assert_eq!(cache.get(&key).unwrap(), path);
+    assert!(cache.is_cached(&key));
+    assert_eq!(fetches.get(), 1);
+
+    cache.get(&InputKey::new(7, Part::Three)).unwrap();
+    assert_eq!(fetches.get(), 2);
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn fixture_dir_serves_inputs_by_key() {
+    let fixtures = temp_dir("fixtures");
+    let key = InputKey::new(3, Part::One);
+    let fixture = fixtures.join(key.relative_path());
+    fs::create_dir_all(fixture.parent().unwrap()).unwrap();
+    fs::write(&fixture, "10,5,1,10,3,8,5,2,2").unwrap();
+
+    let cache_dir = temp_dir("fixture-cache");
+    let cache = InputCache::new(
+        &cache_dir,
+        FixtureDir {
+            root: fixtures.clone(),
+        },
+    );
+    let path = cache.get(&key).unwrap();
+    assert_eq!(fs::read_to_string(path).unwrap(), "10,5,1,10,3,8,5,2,2");
+    assert!(cache.get(&InputKey::new(3, Part::Two)).is_err());
+    assert!(!cache.is_cached(&InputKey::new(3, Part::Two)));
+
+    fs::remove_dir_all(&fixtures).unwrap();
+    fs::remove_dir_all(&cache_dir).unwrap();
+}
+
+#[cfg(feature = "http")]
+mod http {
+    use std::{
+        fs,
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    use common::Part;
+    use ec::inputs::{HttpTransport, InputCache, InputKey};
+
+    use super::temp_dir;
+
+    /// Answers a single request with `body`, returning the request line and headers it received.
+    fn serve_once(body: &'static str) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = format!("http://{}", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let request = BufReader::new(stream.try_clone().unwrap())
+                .lines()
+                .map(Result::unwrap)
+                .take_while(|line| !line.is_empty())
+                .collect::<Vec<String>>();
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            )
+            .unwrap();
+            request
+        });
+        (address, server)
+    }
+
+    #[test]
+    fn http_transport_sends_the_session_cookie() {
+        let (address, server) = serve_once("A=[25,9]\n");
+        let transport = HttpTransport {
+            url_template: format!("{}/inputs/{{event}}/{{quest}}/{{part}}", address),
+            session: "secret".to_string(),
+        };
+        let cache_dir = temp_dir("http");
+        let cache = InputCache::new(&cache_dir, transport);
+        let path = cache.get(&InputKey::new(2, Part::One)).unwrap();
+        assert_eq!(fs::read_to_string(path).unwrap(), "A=[25,9]\n");
+
+        let request = server.join().unwrap();
+        assert_eq!(request[0], "GET /inputs/2025/2/1 HTTP/1.1");
+        assert!(
+            request
+                .iter()
+                .any(|header| header.eq_ignore_ascii_case("cookie: everybody-codes=secret")),
+            "{:?}",
+            request
+        );
+        fs::remove_dir_all(&cache_dir).unwrap();
+    }
+}
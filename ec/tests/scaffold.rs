@@ -0,0 +1,74 @@
+use std::{fs, path::Path};
+
+use ec::{manifest::Manifest, scaffold};
+
+/// A copy of the files `ec new` edits, so the real workspace is left alone.
+fn workspace_copy(name: &str) -> std::path::PathBuf {
+    let source = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let root = std::env::temp_dir().join(format!("ec-scaffold-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(root.join("ec").join("src")).unwrap();
+    for file in ["Cargo.toml", "ec/Cargo.toml", "ec/src/lib.rs"] {
+        fs::copy(source.join(file), root.join(file)).unwrap();
+    }
+    root
+}
+
+#[test]
+fn new_quest_is_wired_into_the_workspace() {
+    let root = workspace_copy("new");
+    let written = scaffold::new_quest(&root, 42).unwrap();
+    let quest_dir = root.join("quest42");
+    for file in [
+        "Cargo.toml",
+        "src/lib.rs",
+        "src/main.rs",
+        "answers.toml",
+        "tests/input/test1.txt",
+    ] {
+        assert!(
+            written.contains(&quest_dir.join(file)),
+            "{} not written",
+            file
+        );
+    }
+    assert!(quest_dir.join("input").is_dir());
+
+    let lib = fs::read_to_string(quest_dir.join("src/lib.rs")).unwrap();
+    assert!(lib.contains("impl Solution for Quest42 {"));
+    let manifest = Manifest::load(&quest_dir).unwrap();
+    assert_eq!(manifest.cases.len(), 1);
+
+    let workspace = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+    assert!(workspace.contains("    \"quest42\",\n]"));
+    assert!(workspace.contains("quest42 = { path = \"quest42\" }"));
+    let ec_manifest = fs::read_to_string(root.join("ec/Cargo.toml")).unwrap();
+    assert!(ec_manifest.contains("quest42.workspace = true"));
+    let ec_lib = fs::read_to_string(root.join("ec/src/lib.rs")).unwrap();
+    assert!(ec_lib.contains("        solve: solve::<quest42::Quest42>,"));
+    assert!(ec_lib.contains("bench: bench_part::<quest42::Quest42>,\n    },\n];"));
+    assert!(toml::from_str::<toml::Value>(&workspace).is_ok());
+
+    assert!(scaffold::new_quest(&root, 42).is_err());
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn failed_registration_leaves_nothing_behind() {
+    let root = workspace_copy("failed");
+    fs::write(root.join("ec/src/lib.rs"), "pub mod manifest;\n").unwrap();
+    let before = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+
+    assert!(scaffold::new_quest(&root, 42).is_err());
+    assert!(!root.join("quest42").exists());
+    assert_eq!(fs::read_to_string(root.join("Cargo.toml")).unwrap(), before);
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn workspace_root_is_found_from_a_subdirectory() {
+    let root = workspace_copy("root");
+    let nested = root.join("ec").join("src");
+    assert_eq!(scaffold::find_workspace_root(&nested).unwrap(), root);
+    fs::remove_dir_all(&root).unwrap();
+}
@@ -0,0 +1,190 @@
+use std::{
+    cell::RefCell,
+    fs,
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
+use common::{Error, Part};
+use ec::{
+    answers::{AnswerLog, AnswerTracker, Submitter, Verdict},
+    inputs::InputKey,
+};
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("ec-submit-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Accepts only `expected` and remembers every submission.
+struct FakeServer {
+    expected: &'static str,
+    submissions: Rc<RefCell<Vec<String>>>,
+}
+
+impl Submitter for FakeServer {
+    fn submit(&self, _key: &InputKey, answer: &str) -> Result<Verdict, Error> {
+        self.submissions.borrow_mut().push(answer.to_string());
+        Ok(if answer == self.expected {
+            Verdict::Correct
+        } else {
+            Verdict::Wrong
+        })
+    }
+}
+
+fn tracker(dir: &Path) -> (AnswerTracker<FakeServer>, Rc<RefCell<Vec<String>>>) {
+    let submissions = Rc::new(RefCell::new(Vec::new()));
+    let server = FakeServer {
+        expected: "42",
+        submissions: submissions.clone(),
+    };
+    (AnswerTracker::new(dir, server), submissions)
+}
+
+#[test]
+fn known_wrong_answers_are_not_resubmitted() {
+    let dir = temp_dir("wrong");
+    let (tracker, submissions) = tracker(&dir);
+    let key = InputKey::new(5, Part::One);
+
+    assert_eq!(tracker.submit(&key, "41").unwrap(), Verdict::Wrong);
+    let error = tracker.submit(&key, "41").unwrap_err();
+    assert!(
+        error.to_string().contains("already known to be wrong"),
+        "{}",
+        error
+    );
+    assert_eq!(tracker.submit(&key, "42").unwrap(), Verdict::Correct);
+    assert_eq!(tracker.submit(&key, "42").unwrap(), Verdict::Correct);
+    assert!(tracker.submit(&key, "43").is_err());
+    assert_eq!(*submissions.borrow(), ["41", "42"]);
+
+    // A new tracker over the same directory remembers the verdicts.
+    let (tracker, submissions) = self::tracker(&dir);
+    assert!(tracker.submit(&key, "41").is_err());
+    assert!(submissions.borrow().is_empty());
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn recorded_answers_are_logged_once() {
+    let dir = temp_dir("record");
+    let (tracker, submissions) = tracker(&dir);
+    let key = InputKey::new(5, Part::Two);
+
+    tracker.record(&key, "7").unwrap();
+    tracker.record(&key, "7").unwrap();
+    tracker.record(&key, "42").unwrap();
+    tracker.submit(&key, "42").unwrap();
+    assert_eq!(submissions.borrow().len(), 1);
+
+    assert_eq!(
+        tracker.path(&key),
+        dir.join("2025").join("quest05").join("part2.answers.toml")
+    );
+    let log = AnswerLog::load(&tracker.path(&key)).unwrap();
+    let summary = log
+        .entries
+        .iter()
+        .map(|entry| (entry.answer.as_str(), entry.submitted, entry.verdict))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        summary,
+        [("7", false, None), ("42", true, Some(Verdict::Correct))]
+    );
+    assert_eq!(log.correct().unwrap().answer, "42");
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[cfg(feature = "http")]
+mod http {
+    use std::{
+        fs,
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    use common::Part;
+    use ec::{
+        answers::{AnswerTracker, HttpSubmitter, Verdict},
+        inputs::InputKey,
+    };
+
+    use super::temp_dir;
+
+    /// Replies to one request with `reply`, returning the request line, headers and body.
+    fn serve_once(reply: &'static str) -> (String, thread::JoinHandle<(Vec<String>, String)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = format!("http://{}", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut head = Vec::new();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let line = line.trim_end().to_string();
+                if line.is_empty() {
+                    break;
+                }
+                head.push(line);
+            }
+            let length = head
+                .iter()
+                .find_map(|header| {
+                    let (name, value) = header.split_once(':')?;
+                    name.eq_ignore_ascii_case("content-length")
+                        .then(|| value.trim().parse::<usize>().unwrap())
+                })
+                .unwrap_or(0);
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                reply.len(),
+                reply
+            )
+            .unwrap();
+            (head, String::from_utf8(body).unwrap())
+        });
+        (address, server)
+    }
+
+    #[test]
+    fn http_submitter_posts_the_answer() {
+        let (address, server) = serve_once("wrong, too low\n");
+        let submitter = HttpSubmitter {
+            url_template: format!("{}/answers/{{event}}/{{quest}}/{{part}}", address),
+            session: "secret".to_string(),
+        };
+        let dir = temp_dir("http");
+        let tracker = AnswerTracker::new(&dir, submitter);
+        let key = InputKey::new(9, Part::Three);
+        assert_eq!(tracker.submit(&key, "1234").unwrap(), Verdict::Wrong);
+
+        let (head, body) = server.join().unwrap();
+        assert_eq!(head[0], "POST /answers/2025/9/3 HTTP/1.1");
+        assert!(
+            head.iter()
+                .any(|header| header.eq_ignore_ascii_case("cookie: everybody-codes=secret")),
+            "{:?}",
+            head
+        );
+        assert_eq!(body, "answer=1234");
+
+        // The server is gone, so the verdict has to come from the log.
+        let error = tracker.submit(&key, "1234").unwrap_err();
+        assert!(
+            error.to_string().contains("already known to be wrong"),
+            "{}",
+            error
+        );
+        assert_eq!(tracker.log(&key).unwrap().entries.len(), 1);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
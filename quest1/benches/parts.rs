@@ -0,0 +1,19 @@
+//! Parse and part timings on the real inputs and the examples: `cargo bench -p quest1 --bench parts`.
+
+use common::Part;
+
+const ITERATIONS: usize = 10;
+
+fn main() {
+    common::bench::main::<quest1::Quest1>(
+        &[
+            (Part::One, "input/input1.txt", &[]),
+            (Part::Two, "input/input2.txt", &[]),
+            (Part::Three, "input/input3.txt", &[]),
+            (Part::One, "tests/input/test1.txt", &[]),
+            (Part::Two, "tests/input/test1.txt", &[]),
+            (Part::Three, "tests/input/test3.txt", &[]),
+        ],
+        ITERATIONS,
+    );
+}
@@ -0,0 +1,101 @@
+use crate::{Instruction, Mode};
+
+/// The net effect of a list of instructions on a wheel of `len` names, so the list can be
+/// applied, or applied many times over, without stepping through it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Composition {
+    /// [`Mode::Wrap`]: the selection moves `offset` names to the right.
+    Shift { len: usize, offset: usize },
+    /// [`Mode::SwapWithHead`] and [`Mode::Rotate`]: the selection stays on the first name
+    /// and the names are reordered, the name at `i` afterwards being the one at `order[i]`.
+    Reorder(Vec<usize>),
+}
+
+impl Composition {
+    /// `None` for modes whose moves depend on where the selection is, i.e. clamping and
+    /// bouncing off the ends. Takes `O(len + instructions)` whatever the repeat counts.
+    pub fn of(mode: Mode, len: usize, instructions: &[Instruction]) -> Option<Self> {
+        assert!(len > 0, "a name wheel needs at least one name");
+        match mode {
+            Mode::Clamp | Mode::Bounce => None,
+            Mode::Wrap => Some(Composition::Shift {
+                len,
+                offset: net_offset(len, instructions),
+            }),
+            Mode::SwapWithHead => {
+                let mut order = (0..len).collect::<Vec<usize>>();
+                // A swap undoes itself, so only odd repeats count.
+                for instruction in instructions.iter().filter(|i| i.repeat % 2 == 1) {
+                    order.swap(0, instruction.offset().rem_euclid(len as i64) as usize);
+                }
+                Some(Composition::Reorder(order))
+            }
+            Mode::Rotate => {
+                let offset = net_offset(len, instructions);
+                Some(Composition::Reorder(
+                    (0..len).map(|i| (i + offset) % len).collect(),
+                ))
+            }
+        }
+    }
+
+    /// Doing nothing to a wheel of `len` names, in the same form as `self`.
+    fn identity_like(&self) -> Self {
+        match self {
+            Composition::Shift { len, .. } => Composition::Shift {
+                len: *len,
+                offset: 0,
+            },
+            Composition::Reorder(order) => Composition::Reorder((0..order.len()).collect()),
+        }
+    }
+
+    /// `self` followed by `next`. Panics if they are for different modes or wheel sizes.
+    pub fn then(&self, next: &Composition) -> Self {
+        match (self, next) {
+            (
+                Composition::Shift { len, offset },
+                Composition::Shift {
+                    len: next_len,
+                    offset: next_offset,
+                },
+            ) if len == next_len => Composition::Shift {
+                len: *len,
+                offset: (offset + next_offset) % len,
+            },
+            (Composition::Reorder(order), Composition::Reorder(next_order))
+                if order.len() == next_order.len() =>
+            {
+                Composition::Reorder(next_order.iter().map(|&i| order[i]).collect())
+            }
+            _ => panic!("can't compose moves of different modes or wheel sizes"),
+        }
+    }
+
+    /// `self` applied `times` times in a row: constant time for a shift, `O(len log times)`
+    /// for a reordering.
+    pub fn repeat(&self, times: u64) -> Self {
+        if let Composition::Shift { len, offset } = self {
+            let offset = (*offset as u128 * times as u128 % *len as u128) as usize;
+            return Composition::Shift { len: *len, offset };
+        }
+        let mut result = self.identity_like();
+        let mut power = self.clone();
+        let mut times = times;
+        while times > 0 {
+            if times & 1 == 1 {
+                result = result.then(&power);
+            }
+            power = power.then(&power);
+            times >>= 1;
+        }
+        result
+    }
+}
+
+/// The sum of the instructions' offsets, repeats included, modulo `len`.
+fn net_offset(len: usize, instructions: &[Instruction]) -> usize {
+    instructions.iter().fold(0, |offset, instruction| {
+        (offset + instruction.total_offset().rem_euclid(len as i128) as usize) % len
+    })
+}
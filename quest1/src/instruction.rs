@@ -0,0 +1,129 @@
+use std::fmt;
+
+use common::{Error, Input};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+impl Direction {
+    /// `L`, `R`, `Left` or `Right`, in any case.
+    pub fn parse(word: &str) -> Option<Self> {
+        match word.to_ascii_lowercase().as_str() {
+            "l" | "left" => Some(Direction::Left),
+            "r" | "right" => Some(Direction::Right),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Direction::Left => write!(f, "L"),
+            Direction::Right => write!(f, "R"),
+        }
+    }
+}
+
+/// A move, made `repeat` times in a row. Repeats stay in this run-length form, so a huge
+/// count costs no more than a single move to store.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    pub direction: Direction,
+    pub distance: u32,
+    /// At least 1.
+    pub repeat: u64,
+}
+
+impl Instruction {
+    /// The distance of a single move, negative to the left.
+    pub fn offset(&self) -> i64 {
+        match self.direction {
+            Direction::Left => -i64::from(self.distance),
+            Direction::Right => i64::from(self.distance),
+        }
+    }
+
+    /// The distance of all `repeat` moves together, which can't overflow an `i128`.
+    pub fn total_offset(&self) -> i128 {
+        i128::from(self.offset()) * i128::from(self.repeat)
+    }
+}
+
+impl fmt::Display for Instruction {
+    /// `R3`, or `R3x4` when repeated.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.direction, self.distance)?;
+        if self.repeat != 1 {
+            write!(f, "x{}", self.repeat)?;
+        }
+        Ok(())
+    }
+}
+
+/// Instructions separated by commas, whitespace or both. Each is a direction, a distance and
+/// an optional repeat count, e.g. `R3`, `Left12` or `R3x4`, which stands for four `R3`s and
+/// is kept as one [`Instruction`] with `repeat` 4. Errors point at the offending instruction and give its 1-based index.
+pub fn parse_instructions(input: &Input, text: &str) -> Result<Vec<Instruction>, Error> {
+    let mut instructions = Vec::new();
+    let tokens = text
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|token| !token.is_empty());
+    for (index, token) in tokens.enumerate() {
+        let invalid = |message: String| {
+            input.invalid_value(token, format!("instruction {}: {}", index + 1, message))
+        };
+
+        let word_end = token
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(token.len());
+        let word = &token[..word_end];
+        let direction = Direction::parse(word).ok_or_else(|| {
+            invalid(format!(
+                "unknown direction \"{}\", expected L, R, Left or Right",
+                word
+            ))
+        })?;
+
+        let rest = &token[word_end..];
+        let digits_end = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        if digits_end == 0 {
+            return Err(invalid(format!("expected a distance after \"{}\"", word)));
+        }
+        let distance = rest[..digits_end]
+            .parse::<u32>()
+            .map_err(|e| invalid(format!("invalid distance: {}", e)))?;
+
+        let repeat = match &rest[digits_end..] {
+            "" => 1,
+            suffix => {
+                let count = suffix
+                    .strip_prefix(['x', 'X'])
+                    .ok_or_else(|| {
+                        invalid(format!(
+                            "unexpected \"{}\" after the distance, expected x and a repeat count",
+                            suffix
+                        ))
+                    })?
+                    .parse::<u64>()
+                    .map_err(|e| invalid(format!("invalid repeat count: {}", e)))?;
+                if count == 0 {
+                    return Err(invalid("the repeat count must be at least 1".to_string()));
+                }
+                count
+            }
+        };
+
+        instructions.push(Instruction {
+            direction,
+            distance,
+            repeat,
+        });
+    }
+    Ok(instructions)
+}
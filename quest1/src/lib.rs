@@ -0,0 +1,76 @@
+pub mod compose;
+pub mod instruction;
+pub mod trace;
+pub mod wheel;
+
+use common::input::fields;
+use common::{Error, Input, Solution};
+
+pub use compose::Composition;
+pub use instruction::{Direction, Instruction, parse_instructions};
+pub use wheel::{Mode, NameWheel};
+
+pub fn part1(names: &[String], instructions: &[Instruction]) -> String {
+    NameWheel::new(names, Mode::Clamp)
+        .run(instructions)
+        .to_string()
+}
+
+pub fn part2(names: &[String], instructions: &[Instruction]) -> String {
+    NameWheel::new(names, Mode::Wrap)
+        .run_repeated(instructions, 1)
+        .to_string()
+}
+
+pub fn part3(names: &[String], instructions: &[Instruction]) -> String {
+    NameWheel::new(names, Mode::SwapWithHead)
+        .run_repeated(instructions, 1)
+        .to_string()
+}
+
+pub fn extract_input_from_file(file_path: &str) -> Result<(Vec<String>, Vec<Instruction>), Error> {
+    parse_input(&Input::open(file_path)?)
+}
+
+/// A line of comma separated names, then the instructions, see [`parse_instructions`].
+pub fn parse_input(input: &Input) -> Result<(Vec<String>, Vec<Instruction>), Error> {
+    let text = input.trimmed();
+    let (names_line, rest) = text
+        .split_once('\n')
+        .ok_or_else(|| input.format_error(text, "expected instructions after the names"))?;
+    let names = fields(names_line, ',')
+        .map(|name| name.to_string())
+        .collect::<Vec<String>>();
+
+    let instructions = parse_instructions(input, rest)?;
+    if instructions.is_empty() {
+        return Err(input.format_error(names_line, "expected instructions after the names"));
+    }
+    Ok((names, instructions))
+}
+
+pub struct Quest1;
+
+impl Solution for Quest1 {
+    type Input = (Vec<String>, Vec<Instruction>);
+    type Params = ();
+    type Answer1 = String;
+    type Answer2 = String;
+    type Answer3 = String;
+
+    fn parse_input(input: &Input) -> Result<Self::Input, Error> {
+        parse_input(input)
+    }
+
+    fn part1((names, instructions): &Self::Input, _: &()) -> Result<String, Error> {
+        Ok(part1(names, instructions))
+    }
+
+    fn part2((names, instructions): &Self::Input, _: &()) -> Result<String, Error> {
+        Ok(part2(names, instructions))
+    }
+
+    fn part3((names, instructions): &Self::Input, _: &()) -> Result<String, Error> {
+        Ok(part3(names, instructions))
+    }
+}
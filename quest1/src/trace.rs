@@ -0,0 +1,167 @@
+use std::{
+    fs,
+    io::{self, Write},
+    path::Path,
+    str::FromStr,
+};
+
+use common::{Error, Part, part_params};
+
+use crate::{Instruction, Mode, NameWheel, Quest1, extract_input_from_file};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceFormat {
+    Table,
+    Csv,
+}
+
+impl FromStr for TraceFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(TraceFormat::Table),
+            "csv" => Ok(TraceFormat::Csv),
+            _ => Err(Error::param(
+                "trace",
+                format!("expected table or csv, got \"{}\"", s),
+            )),
+        }
+    }
+}
+
+/// The wheel after one instruction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    /// 1-based.
+    pub number: usize,
+    pub instruction: String,
+    pub position: usize,
+    pub name: String,
+    /// The whole list, for modes that reorder it.
+    pub names: Option<Vec<String>>,
+}
+
+impl NameWheel {
+    /// Applies the instructions one by one, yielding the wheel after each.
+    pub fn trace<'a>(
+        mut self,
+        instructions: impl IntoIterator<Item = &'a Instruction> + 'a,
+    ) -> impl Iterator<Item = Step> + 'a {
+        instructions
+            .into_iter()
+            .enumerate()
+            .map(move |(index, instruction)| {
+                self.apply(instruction);
+                Step {
+                    number: index + 1,
+                    instruction: instruction.to_string(),
+                    position: self.position(),
+                    name: self.current().to_string(),
+                    names: self.mode().reorders().then(|| self.names().to_vec()),
+                }
+            })
+    }
+}
+
+fn header(with_names: bool) -> Vec<String> {
+    let mut header = ["step", "instruction", "position", "name"]
+        .map(String::from)
+        .to_vec();
+    if with_names {
+        header.push("names".to_string());
+    }
+    header
+}
+
+fn row(step: &Step, separator: &str) -> Vec<String> {
+    let mut row = vec![
+        step.number.to_string(),
+        step.instruction.clone(),
+        step.position.to_string(),
+        step.name.clone(),
+    ];
+    if let Some(names) = &step.names {
+        row.push(names.join(separator));
+    }
+    row
+}
+
+/// Columns padded to their widest cell, names separated by spaces.
+pub fn render_table(steps: &[Step], with_names: bool) -> String {
+    let rows = std::iter::once(header(with_names))
+        .chain(steps.iter().map(|step| row(step, " ")))
+        .collect::<Vec<Vec<String>>>();
+    let mut widths = vec![0; rows[0].len()];
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    rows.iter()
+        .map(|row| {
+            let line = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+                .collect::<Vec<String>>()
+                .join("  ");
+            line.trim_end().to_string() + "\n"
+        })
+        .collect()
+}
+
+/// Names of the `names` column are comma separated, so it's quoted.
+pub fn render_csv(steps: &[Step], with_names: bool) -> String {
+    std::iter::once(header(with_names))
+        .chain(steps.iter().map(|step| row(step, ",")))
+        .map(|row| {
+            row.iter()
+                .map(|cell| csv_field(cell))
+                .collect::<Vec<String>>()
+                .join(",")
+                + "\n"
+        })
+        .collect()
+}
+
+fn csv_field(cell: &str) -> String {
+    if cell.contains([',', '"', '\n']) {
+        format!("\"{}\"", cell.replace('"', "\"\""))
+    } else {
+        cell.to_string()
+    }
+}
+
+/// Solves `part` like [`common::solve`] while writing every step to `out` or stdout.
+pub fn trace(
+    file_path: &str,
+    part: Part,
+    params: &[(String, String)],
+    format: &str,
+    out: Option<&Path>,
+) -> Result<String, Error> {
+    let format = format.parse::<TraceFormat>()?;
+    part_params::<Quest1>(part, params)?;
+    let (names, instructions) = extract_input_from_file(file_path)?;
+    if names.is_empty() {
+        return Err(Error::unsolvable("there are no names"));
+    }
+    let mode = Mode::for_part(part);
+    let steps = NameWheel::new(&names, mode)
+        .trace(&instructions)
+        .collect::<Vec<Step>>();
+
+    let rendered = match format {
+        TraceFormat::Table => render_table(&steps, mode.reorders()),
+        TraceFormat::Csv => render_csv(&steps, mode.reorders()),
+    };
+    match out {
+        Some(out) => fs::write(out, rendered).map_err(|e| Error::io(out, e))?,
+        None => io::stdout()
+            .write_all(rendered.as_bytes())
+            .map_err(|e| Error::io("stdout", e))?,
+    }
+
+    Ok(steps.last().map_or(&names[0], |step| &step.name).clone())
+}
@@ -0,0 +1,171 @@
+use common::Part;
+
+use crate::{Composition, Instruction};
+
+/// How an instruction moves the selection, or the names, of a [`NameWheel`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Stops at the first and last name (part 1).
+    Clamp,
+    /// Continues from the other end (part 2).
+    Wrap,
+    /// Swaps the first name with the one the instruction points at, counting around the wheel
+    /// from the first (part 3). The selection stays on the first name.
+    SwapWithHead,
+    /// Reflects off the first and last name, e.g. `R2` from the last of five names lands on the
+    /// third.
+    Bounce,
+    /// Turns the whole list so the name the instruction points at becomes the first one.
+    Rotate,
+}
+
+impl Mode {
+    /// The mode the puzzle uses for `part`.
+    pub fn for_part(part: Part) -> Self {
+        match part {
+            Part::One => Mode::Clamp,
+            Part::Two => Mode::Wrap,
+            Part::Three => Mode::SwapWithHead,
+        }
+    }
+
+    /// Whether the mode moves names instead of the selection.
+    pub fn reorders(self) -> bool {
+        matches!(self, Mode::SwapWithHead | Mode::Rotate)
+    }
+}
+
+/// A list of names with one of them selected, moved around by [`Instruction`]s.
+#[derive(Debug, Clone)]
+pub struct NameWheel {
+    names: Vec<String>,
+    position: usize,
+    mode: Mode,
+}
+
+impl NameWheel {
+    /// Starts on the first name. Panics if `names` is empty.
+    pub fn new(names: &[String], mode: Mode) -> Self {
+        assert!(!names.is_empty(), "a name wheel needs at least one name");
+        NameWheel {
+            names: names.to_vec(),
+            position: 0,
+            mode,
+        }
+    }
+
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn current(&self) -> &str {
+        &self.names[self.position]
+    }
+
+    /// Makes the instruction's move `repeat` times, in time independent of `repeat` except
+    /// when bouncing, which takes at most one step per name.
+    pub fn apply(&mut self, instruction: &Instruction) {
+        let len = self.names.len() as i128;
+        let offset = instruction.offset();
+        let target = self.position as i128 + instruction.total_offset();
+        match self.mode {
+            // Repeated moves go the same way, so they add up.
+            Mode::Clamp => self.position = target.clamp(0, len - 1) as usize,
+            Mode::Wrap => self.position = target.rem_euclid(len) as usize,
+            // A swap undoes itself.
+            Mode::SwapWithHead => {
+                if instruction.repeat % 2 == 1 {
+                    self.names
+                        .swap(0, (offset as i128).rem_euclid(len) as usize);
+                }
+            }
+            Mode::Bounce => self.bounce(offset, instruction.repeat),
+            Mode::Rotate => self
+                .names
+                .rotate_left(instruction.total_offset().rem_euclid(len) as usize),
+        }
+    }
+
+    /// A bounce forgets which way it was going, so repeats don't add up. They do run into a
+    /// cycle within one pass over the names, after which only the remainder matters.
+    fn bounce(&mut self, offset: i64, repeat: u64) {
+        let len = self.names.len() as i64;
+        // Walking there and back is periodic, so only the remainder matters.
+        let period = 2 * (len - 1);
+        let step = |position: usize| {
+            let unfolded = (position as i64 + offset).rem_euclid(period.max(1));
+            let position = if unfolded < len {
+                unfolded
+            } else {
+                period - unfolded
+            };
+            position as usize
+        };
+
+        let mut seen = vec![None; self.names.len()];
+        let mut done = 0;
+        while done < repeat {
+            if let Some(first) = seen[self.position] {
+                let remaining = (repeat - done) % (done - first);
+                for _ in 0..remaining {
+                    self.position = step(self.position);
+                }
+                return;
+            }
+            seen[self.position] = Some(done);
+            self.position = step(self.position);
+            done += 1;
+        }
+    }
+
+    /// Applies a [`Composition`] made for this wheel's mode and size.
+    pub fn apply_composition(&mut self, composition: &Composition) {
+        match composition {
+            Composition::Shift { len, offset } => {
+                assert_eq!(*len, self.names.len(), "composition for another wheel size");
+                self.position = (self.position + offset) % len;
+            }
+            Composition::Reorder(order) => {
+                assert_eq!(
+                    order.len(),
+                    self.names.len(),
+                    "composition for another wheel size"
+                );
+                self.names = order.iter().map(|&i| self.names[i].clone()).collect();
+            }
+        }
+    }
+
+    /// Applies the whole instruction list `times` times in a row and returns the selected name.
+    /// Modes with a [`Composition`] take time independent of `times` (logarithmic for
+    /// reordering), the others step through every instruction.
+    pub fn run_repeated(&mut self, instructions: &[Instruction], times: u64) -> &str {
+        match Composition::of(self.mode, self.names.len(), instructions) {
+            Some(composition) => self.apply_composition(&composition.repeat(times)),
+            None => {
+                for _ in 0..times {
+                    for instruction in instructions {
+                        self.apply(instruction);
+                    }
+                }
+            }
+        }
+        self.current()
+    }
+
+    /// Applies every instruction and returns the selected name.
+    pub fn run<'a>(&mut self, instructions: impl IntoIterator<Item = &'a Instruction>) -> &str {
+        for instruction in instructions {
+            self.apply(instruction);
+        }
+        self.current()
+    }
+}
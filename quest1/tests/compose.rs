@@ -0,0 +1,198 @@
+//! Property checks of [`Composition`] against stepping a [`NameWheel`], over seeded random
+//! wheels and instruction lists.
+
+use quest1::{Composition, Direction, Instruction, Mode, NameWheel};
+
+const CASES: usize = 500;
+const COMPOSABLE: [Mode; 3] = [Mode::Wrap, Mode::SwapWithHead, Mode::Rotate];
+
+/// xorshift64, so failures reproduce without a dependency.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, bound: u64) -> u64 {
+        self.next() % bound
+    }
+
+    fn names(&mut self) -> Vec<String> {
+        (0..1 + self.below(12)).map(|i| format!("N{}", i)).collect()
+    }
+
+    /// Mostly single moves, some repeated a few times.
+    fn instructions(&mut self) -> Vec<Instruction> {
+        (0..self.below(20))
+            .map(|_| Instruction {
+                direction: self.direction(),
+                distance: self.below(60) as u32,
+                repeat: if self.below(3) == 0 {
+                    2 + self.below(5)
+                } else {
+                    1
+                },
+            })
+            .collect()
+    }
+
+    fn direction(&mut self) -> Direction {
+        if self.below(2) == 0 {
+            Direction::Left
+        } else {
+            Direction::Right
+        }
+    }
+
+    fn mode(&mut self) -> Mode {
+        COMPOSABLE[self.below(COMPOSABLE.len() as u64) as usize]
+    }
+}
+
+/// One single move at a time, repeats written out.
+fn stepped(names: &[String], mode: Mode, instructions: &[Instruction], times: u64) -> NameWheel {
+    let mut wheel = NameWheel::new(names, mode);
+    for _ in 0..times {
+        for instruction in instructions {
+            let single = Instruction {
+                repeat: 1,
+                ..*instruction
+            };
+            for _ in 0..instruction.repeat {
+                wheel.apply(&single);
+            }
+        }
+    }
+    wheel
+}
+
+fn assert_same(left: &NameWheel, right: &NameWheel, context: &str) {
+    assert_eq!(left.names(), right.names(), "{}", context);
+    assert_eq!(left.position(), right.position(), "{}", context);
+}
+
+#[test]
+fn composition_matches_stepping() {
+    let mut rng = Rng(0x5eed_1234_abcd_0001);
+    for case in 0..CASES {
+        let (names, instructions, mode) = (rng.names(), rng.instructions(), rng.mode());
+        let times = rng.below(30);
+        let context = format!(
+            "case {}: {:?} x{} on {} names",
+            case,
+            mode,
+            times,
+            names.len()
+        );
+
+        let mut composed = NameWheel::new(&names, mode);
+        composed.run_repeated(&instructions, times);
+        assert_same(
+            &composed,
+            &stepped(&names, mode, &instructions, times),
+            &context,
+        );
+    }
+}
+
+#[test]
+fn composing_lists_matches_concatenating_them() {
+    let mut rng = Rng(0x5eed_1234_abcd_0002);
+    for case in 0..CASES {
+        let (names, mode) = (rng.names(), rng.mode());
+        let (first, second) = (rng.instructions(), rng.instructions());
+        let both = first.iter().chain(&second).copied().collect::<Vec<_>>();
+
+        let of = |instructions: &[Instruction]| {
+            Composition::of(mode, names.len(), instructions).unwrap()
+        };
+        assert_eq!(of(&first).then(&of(&second)), of(&both), "case {}", case);
+    }
+}
+
+#[test]
+fn huge_repeat_counts_reduce_by_the_period() {
+    let mut rng = Rng(0x5eed_1234_abcd_0003);
+    let times = 1_000_000_000_000;
+    for case in 0..CASES {
+        let (names, instructions, mode) = (rng.names(), rng.instructions(), rng.mode());
+        let composition = Composition::of(mode, names.len(), &instructions).unwrap();
+
+        // Every composition returns to doing nothing after some number of repeats.
+        let identity = composition.repeat(0);
+        let period = (1..=u64::MAX)
+            .find(|&n| composition.repeat(n) == identity)
+            .unwrap();
+
+        let mut composed = NameWheel::new(&names, mode);
+        composed.run_repeated(&instructions, times);
+        let expected = stepped(&names, mode, &instructions, times % period);
+        assert_same(&composed, &expected, &format!("case {}: {:?}", case, mode));
+    }
+}
+
+#[test]
+fn huge_instruction_repeats_fold_by_exponentiation() {
+    let mut rng = Rng(0x5eed_1234_abcd_0004);
+    for case in 0..CASES {
+        let (names, mode) = (rng.names(), rng.mode());
+        // Far more single moves than could ever be written out.
+        let repeat = u64::MAX - rng.below(1 << 32);
+        let instruction = Instruction {
+            direction: rng.direction(),
+            distance: rng.below(60) as u32,
+            repeat,
+        };
+        let single = Instruction {
+            repeat: 1,
+            ..instruction
+        };
+
+        let of = |instructions: &[Instruction]| {
+            Composition::of(mode, names.len(), instructions).unwrap()
+        };
+        let context = format!("case {}: {:?} {}", case, mode, instruction);
+        assert_eq!(
+            of(&[instruction]),
+            of(&[single]).repeat(repeat),
+            "{}",
+            context
+        );
+
+        // Applying it directly folds it the same way.
+        let mut applied = NameWheel::new(&names, mode);
+        applied.apply(&instruction);
+        let mut composed = NameWheel::new(&names, mode);
+        composed.apply_composition(&of(&[instruction]));
+        assert_same(&applied, &composed, &context);
+    }
+
+    let names = ["A", "B", "C", "D", "E", "F", "G"].map(String::from);
+    let instructions = [
+        Instruction {
+            direction: Direction::Right,
+            distance: 3,
+            repeat: 10u64.pow(18) + 2,
+        },
+        Instruction {
+            direction: Direction::Left,
+            distance: 1,
+            repeat: 1,
+        },
+    ];
+    // 3 * (10^18 + 2) - 1 is 1 more than a multiple of 7, and so is 10^12.
+    for (times, expected) in [(1, "B"), (2, "C"), (1_000_000_000_000, "B")] {
+        let mut wheel = NameWheel::new(&names, Mode::Wrap);
+        assert_eq!(wheel.run_repeated(&instructions, times), expected);
+    }
+}
+
+#[test]
+fn position_dependent_modes_do_not_compose() {
+    assert_eq!(Composition::of(Mode::Clamp, 3, &[]), None);
+    assert_eq!(Composition::of(Mode::Bounce, 3, &[]), None);
+}
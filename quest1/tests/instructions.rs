@@ -0,0 +1,130 @@
+use common::Input;
+use quest1::{Direction, Instruction, Mode, NameWheel, parse_input};
+
+fn parse(text: &str) -> Result<Vec<Instruction>, common::Error> {
+    parse_input(&Input::from(format!("A,B,C\n\n{}", text))).map(|(_, instructions)| instructions)
+}
+
+fn rendered(text: &str) -> String {
+    parse(text)
+        .unwrap()
+        .iter()
+        .map(Instruction::to_string)
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+#[test]
+fn separators_and_repeats() {
+    assert_eq!(rendered("R3,L2"), "R3,L2");
+    assert_eq!(rendered("R3, L2\nR1\tL4\n\nR5"), "R3,L2,R1,L4,R5");
+    assert_eq!(rendered("R3x3,L1"), "R3x3,L1");
+    assert_eq!(rendered("Left2 right1 l7X2 R4x1"), "L2,R1,L7x2,R4");
+    assert_eq!(
+        parse("R3x4").unwrap(),
+        [Instruction {
+            direction: Direction::Right,
+            distance: 3,
+            repeat: 4
+        }]
+    );
+}
+
+#[test]
+fn repeats_are_kept_run_length() {
+    let instructions = parse("L1,R3x4000000000").unwrap();
+    assert_eq!(instructions.len(), 2);
+    assert_eq!(instructions[1].repeat, 4_000_000_000);
+    assert_eq!(instructions[1].total_offset(), 12_000_000_000);
+
+    let names = ["A", "B", "C", "D", "E"].map(String::from);
+    for (mode, expected) in [
+        (Mode::Clamp, "E"),
+        (Mode::Wrap, "E"),
+        (Mode::SwapWithHead, "E"),
+        (Mode::Bounce, "B"),
+        (Mode::Rotate, "E"),
+    ] {
+        assert_eq!(
+            NameWheel::new(&names, mode).run(&instructions),
+            expected,
+            "{:?}",
+            mode
+        );
+    }
+
+    let max = parse(&format!("R1x{}", u64::MAX)).unwrap();
+    assert_eq!(max[0].repeat, u64::MAX);
+}
+
+#[test]
+fn repeating_matches_writing_the_move_out() {
+    let names = ["A", "B", "C", "D", "E", "F", "G"].map(String::from);
+    for mode in [
+        Mode::Clamp,
+        Mode::Wrap,
+        Mode::SwapWithHead,
+        Mode::Bounce,
+        Mode::Rotate,
+    ] {
+        for distance in 0..15 {
+            for repeat in 1..20 {
+                let repeated = parse(&format!("R1,L{}x{}", distance, repeat)).unwrap();
+                let written =
+                    parse(&format!("R1{}", format!(",L{}", distance).repeat(repeat))).unwrap();
+                let mut left = NameWheel::new(&names, mode);
+                let mut right = NameWheel::new(&names, mode);
+                left.run(&repeated);
+                right.run(&written);
+                let context = format!("{:?} L{}x{}", mode, distance, repeat);
+                assert_eq!(left.names(), right.names(), "{}", context);
+                assert_eq!(left.position(), right.position(), "{}", context);
+            }
+        }
+    }
+}
+
+#[test]
+fn errors_name_the_offending_instruction() {
+    for (text, token, message) in [
+        ("R3,U2", "U2", "instruction 2: unknown direction \"U\""),
+        (
+            "R3 L1 R",
+            "R",
+            "instruction 3: expected a distance after \"R\"",
+        ),
+        (
+            "R3y",
+            "R3y",
+            "instruction 1: unexpected \"y\" after the distance",
+        ),
+        (
+            "L1,R2x0",
+            "R2x0",
+            "instruction 2: the repeat count must be at least 1",
+        ),
+        (
+            "R99999999999",
+            "R99999999999",
+            "instruction 1: invalid distance",
+        ),
+        (
+            "R1,L2x99999999999999999999",
+            "L2x99999999999999999999",
+            "instruction 2: invalid repeat count",
+        ),
+        ("3R", "3R", "instruction 1: unknown direction \"\""),
+    ] {
+        let error = parse(text).unwrap_err();
+        let location = error.location().expect("located error");
+        assert_eq!(location.token, token, "{}", text);
+        assert_eq!(location.line, 3, "{}", text);
+        assert!(error.to_string().contains(message), "{}: {}", text, error);
+    }
+}
+
+#[test]
+fn missing_instructions_are_an_error() {
+    assert!(parse_input(&Input::from("A,B,C")).is_err());
+    assert!(parse_input(&Input::from("A,B,C\n\n , ")).is_err());
+}
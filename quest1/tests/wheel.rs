@@ -0,0 +1,87 @@
+use quest1::{Instruction, Mode, NameWheel};
+
+fn names() -> Vec<String> {
+    ["A", "B", "C", "D", "E"].map(String::from).to_vec()
+}
+
+fn instructions(text: &str) -> Vec<Instruction> {
+    quest1::parse_input(&common::Input::from(format!("x\n\n{}", text)))
+        .unwrap()
+        .1
+}
+
+#[test]
+fn movement_modes() {
+    let cases = [
+        (Mode::Clamp, "R7,L1", "D"),
+        (Mode::Wrap, "L1,L5,R12", "B"),
+        (Mode::SwapWithHead, "R2,L1", "E"),
+        (Mode::Bounce, "R6", "C"),
+        (Mode::Bounce, "L3", "D"),
+        (Mode::Bounce, "R4,R1,L10", "B"),
+        (Mode::Rotate, "R2,L6", "B"),
+    ];
+    for (mode, text, expected) in cases {
+        let mut wheel = NameWheel::new(&names(), mode);
+        assert_eq!(
+            wheel.run(&instructions(text)),
+            expected,
+            "{:?} {}",
+            mode,
+            text
+        );
+    }
+}
+
+#[test]
+fn swap_and_rotate_keep_the_selection_on_the_first_name() {
+    let mut wheel = NameWheel::new(&names(), Mode::SwapWithHead);
+    wheel.run(&instructions("R2,L1"));
+    assert_eq!(wheel.position(), 0);
+    assert_eq!(wheel.names(), ["E", "B", "A", "D", "C"]);
+
+    let mut wheel = NameWheel::new(&names(), Mode::Rotate);
+    wheel.run(&instructions("L1"));
+    assert_eq!(wheel.names(), ["E", "A", "B", "C", "D"]);
+}
+
+#[test]
+fn a_single_name_never_moves() {
+    for mode in [
+        Mode::Clamp,
+        Mode::Wrap,
+        Mode::SwapWithHead,
+        Mode::Bounce,
+        Mode::Rotate,
+    ] {
+        let mut wheel = NameWheel::new(&["A".to_string()], mode);
+        assert_eq!(wheel.run(&instructions("R3,L8")), "A");
+    }
+}
+
+#[test]
+fn trace_reports_every_step() {
+    let steps = NameWheel::new(&names(), Mode::SwapWithHead)
+        .trace(&instructions("R2,L1"))
+        .collect::<Vec<_>>();
+    assert_eq!(steps.len(), 2);
+    assert_eq!((steps[1].number, steps[1].instruction.as_str()), (2, "L1"));
+    assert_eq!(steps[1].name, "E");
+
+    let csv = quest1::trace::render_csv(&steps, true);
+    assert_eq!(
+        csv,
+        "step,instruction,position,name,names\n\
+         1,R2,0,C,\"C,B,A,D,E\"\n\
+         2,L1,0,E,\"E,B,A,D,C\"\n"
+    );
+
+    let steps = NameWheel::new(&names(), Mode::Clamp)
+        .trace(&instructions("R7,L1"))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        steps.iter().map(|step| step.position).collect::<Vec<_>>(),
+        [4, 3]
+    );
+    assert!(steps.iter().all(|step| step.names.is_none()));
+}
@@ -0,0 +1,23 @@
+//! Parse and part timings on the real inputs and the examples: `cargo bench -p quest10 --bench parts`.
+
+use common::Part;
+
+const ITERATIONS: usize = 10;
+
+fn main() {
+    common::bench::main::<quest10::Quest10>(
+        &[
+            (Part::One, "input/input1.txt", &[]),
+            (Part::Two, "input/input2.txt", &[]),
+            (Part::Three, "input/input3.txt", &[]),
+            (Part::One, "tests/input/test1.txt", &[("turns", "3")]),
+            (Part::Two, "tests/input/test2.txt", &[("rounds", "3")]),
+            (Part::Three, "tests/input/test3_1.txt", &[]),
+            (Part::Three, "tests/input/test3_2.txt", &[]),
+            (Part::Three, "tests/input/test3_3.txt", &[]),
+            (Part::Three, "tests/input/test3_4.txt", &[]),
+            (Part::Three, "tests/input/test3_5.txt", &[]),
+        ],
+        ITERATIONS,
+    );
+}
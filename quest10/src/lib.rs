@@ -0,0 +1,260 @@
+use common::bitboard::{Bitboard, MoveSet, reachable};
+use common::grid::{Grid, KNIGHT_MOVES, Position};
+use common::{Error, Input, Params, Part, Solution, parse_param};
+use std::collections::HashMap;
+use std::fmt;
+
+pub mod trace;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum TileValue {
+    Empty,
+    Sheep,
+    Dragon,
+    Hideout,
+}
+
+impl TryFrom<char> for TileValue {
+    type Error = String;
+
+    fn try_from(symbol: char) -> Result<Self, Self::Error> {
+        match symbol {
+            '.' => Ok(TileValue::Empty),
+            'S' => Ok(TileValue::Sheep),
+            'D' => Ok(TileValue::Dragon),
+            '#' => Ok(TileValue::Hideout),
+            _ => Err("expected one of '.', 'S', 'D' or '#'".to_string()),
+        }
+    }
+}
+
+impl fmt::Display for TileValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbol = match self {
+            TileValue::Empty => '.',
+            TileValue::Sheep => 'S',
+            TileValue::Dragon => 'D',
+            TileValue::Hideout => '#',
+        };
+        write!(f, "{}", symbol)
+    }
+}
+
+pub type Chessboard = Grid<TileValue>;
+
+fn find_dragon(chessboard: &Chessboard) -> Result<Position, Error> {
+    chessboard
+        .find(|tile| *tile == TileValue::Dragon)
+        .ok_or_else(|| Error::unsolvable("No dragon found on board"))
+}
+
+/// Turn-alternating game of part 3. Cells are addressed by their row-major index.
+struct SheepDragonGame<'a> {
+    chessboard: &'a Chessboard,
+    memo: HashMap<(usize, Vec<usize>), u128>,
+}
+
+impl SheepDragonGame<'_> {
+    fn is_hideout(&self, cell: usize) -> bool {
+        self.chessboard.cells()[cell] == TileValue::Hideout
+    }
+
+    /// Sequences won by the dragon when it's the sheep's turn. Exactly one sheep steps down,
+    /// but never onto the dragon unless that cell is a hideout. Stepping off the board is an
+    /// escape, which ends the game without the dragon winning. Sheep with no legal move at all
+    /// pass the turn to the dragon.
+    fn count_sheep_turn(&mut self, dragon: usize, sheep: Vec<usize>) -> u128 {
+        let key = (dragon, sheep);
+        if let Some(&count) = self.memo.get(&key) {
+            return count;
+        }
+        let (dragon, sheep) = key;
+
+        let mut any_move = false;
+        let mut result = 0;
+        for (index, &cell) in sheep.iter().enumerate() {
+            let next_cell = cell + self.chessboard.columns();
+            if next_cell >= self.chessboard.len() {
+                any_move = true;
+                continue;
+            }
+            if next_cell == dragon && !self.is_hideout(next_cell) {
+                continue;
+            }
+            any_move = true;
+            let mut moved_sheep = sheep.clone();
+            moved_sheep[index] = next_cell;
+            moved_sheep.sort_unstable();
+            result += self.count_dragon_turn(dragon, &moved_sheep);
+        }
+        if !any_move {
+            result = self.count_dragon_turn(dragon, &sheep);
+        }
+
+        self.memo.insert((dragon, sheep), result);
+        result
+    }
+
+    /// Sequences won by the dragon when it's the dragon's turn. It makes one knight move and
+    /// eats the sheep it lands on, unless the sheep hides in a hideout.
+    fn count_dragon_turn(&mut self, dragon: usize, sheep: &[usize]) -> u128 {
+        let chessboard = self.chessboard;
+        let mut result = 0;
+        for next_position in chessboard.moves(chessboard.position_of(dragon), &KNIGHT_MOVES) {
+            let next_dragon = chessboard.index_of(next_position);
+            let remaining_sheep = sheep
+                .iter()
+                .copied()
+                .filter(|&cell| cell != next_dragon || self.is_hideout(cell))
+                .collect::<Vec<usize>>();
+            result += if remaining_sheep.is_empty() {
+                1
+            } else {
+                self.count_sheep_turn(next_dragon, remaining_sheep)
+            };
+        }
+        result
+    }
+}
+
+pub fn part3(chessboard: &Chessboard) -> Result<u128, Error> {
+    let dragon = chessboard.index_of(find_dragon(chessboard)?);
+    let sheep = chessboard
+        .iter()
+        .filter(|(_, tile)| **tile == TileValue::Sheep)
+        .map(|(position, _)| chessboard.index_of(position))
+        .collect::<Vec<usize>>();
+    if sheep.is_empty() {
+        return Err(Error::unsolvable("No sheep found on board"));
+    }
+
+    let mut game = SheepDragonGame {
+        chessboard,
+        memo: HashMap::new(),
+    };
+    Ok(game.count_sheep_turn(dragon, sheep))
+}
+
+pub fn part1(chessboard: &Chessboard, turns: usize) -> Result<usize, Error> {
+    let dragon = find_dragon(chessboard)?;
+    let mut start = Bitboard::new(chessboard.rows(), chessboard.columns());
+    start.insert(dragon);
+    let sheep = Bitboard::from_grid(chessboard, |tile| *tile == TileValue::Sheep);
+    Ok((reachable(&start, &KNIGHT_MOVES, turns).within(turns) & &sheep).count())
+}
+
+/// Part 2 simulation: every round the dragons spread by a knight move, then every sheep
+/// steps down, and sheep sharing a cell with a dragon outside a hideout are eaten.
+pub struct Pasture {
+    knight: MoveSet,
+    hideouts: Bitboard,
+    pub round: usize,
+    /// Cells the dragons can be on after `round` moves.
+    pub dragons: Bitboard,
+    pub sheep: Bitboard,
+    /// Cells where sheep were eaten during the last round.
+    pub eaten: Bitboard,
+    pub eaten_total: usize,
+}
+
+impl Pasture {
+    pub fn new(chessboard: &Chessboard) -> Result<Self, Error> {
+        let (rows, columns) = (chessboard.rows(), chessboard.columns());
+        let mut dragons = Bitboard::new(rows, columns);
+        dragons.insert(find_dragon(chessboard)?);
+        Ok(Pasture {
+            knight: MoveSet::new(rows, columns, &KNIGHT_MOVES),
+            hideouts: Bitboard::from_grid(chessboard, |tile| *tile == TileValue::Hideout),
+            round: 0,
+            dragons,
+            sheep: Bitboard::from_grid(chessboard, |tile| *tile == TileValue::Sheep),
+            eaten: Bitboard::new(rows, columns),
+            eaten_total: 0,
+        })
+    }
+
+    pub fn hideouts(&self) -> &Bitboard {
+        &self.hideouts
+    }
+
+    pub fn next_round(&mut self) {
+        self.round += 1;
+        self.dragons = self.knight.step(&self.dragons);
+        let exposed = self.dragons.and_not(&self.hideouts);
+        self.eaten = &self.sheep & &exposed;
+        self.eaten_total += self.eaten.count();
+        self.sheep = self.sheep.and_not(&exposed);
+
+        // Sheep on the last row step off the board and are dropped by the shift.
+        self.sheep = self.sheep.shifted(self.sheep.columns() as isize);
+        let eaten = &self.sheep & &exposed;
+        self.sheep = self.sheep.and_not(&exposed);
+        self.eaten_total += eaten.count();
+        self.eaten |= &eaten;
+    }
+}
+
+pub fn part2(chessboard: &Chessboard, rounds: usize) -> Result<usize, Error> {
+    let mut pasture = Pasture::new(chessboard)?;
+    for _ in 1..=rounds {
+        pasture.next_round();
+    }
+    Ok(pasture.eaten_total)
+}
+
+pub fn extract_input_from_file(file_path: &str) -> Result<Chessboard, Error> {
+    parse_input(&Input::open(file_path)?)
+}
+
+pub fn parse_input(input: &Input) -> Result<Chessboard, Error> {
+    Grid::parse(input.text(), TileValue::try_from).map_err(|e| input.with_path(e))
+}
+
+pub struct Quest10Params {
+    pub turns: usize,
+    pub rounds: usize,
+}
+
+impl Params for Quest10Params {
+    fn for_part(_part: Part) -> Self {
+        Quest10Params {
+            turns: 4,
+            rounds: 20,
+        }
+    }
+
+    fn set(&mut self, key: &str, value: &str) -> Result<(), Error> {
+        match key {
+            "turns" => self.turns = parse_param(key, value)?,
+            "rounds" => self.rounds = parse_param(key, value)?,
+            _ => return Err(Error::param(key, "unknown param")),
+        }
+        Ok(())
+    }
+}
+
+pub struct Quest10;
+
+impl Solution for Quest10 {
+    type Input = Chessboard;
+    type Params = Quest10Params;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Answer3 = u128;
+
+    fn parse_input(input: &Input) -> Result<Self::Input, Error> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Chessboard, params: &Quest10Params) -> Result<usize, Error> {
+        part1(input, params.turns)
+    }
+
+    fn part2(input: &Chessboard, params: &Quest10Params) -> Result<usize, Error> {
+        part2(input, params.rounds)
+    }
+
+    fn part3(input: &Chessboard, _: &Quest10Params) -> Result<u128, Error> {
+        part3(input)
+    }
+}
@@ -0,0 +1,248 @@
+use std::{
+    fmt, fs,
+    io::{self, Write},
+    path::Path,
+    str::FromStr,
+};
+
+use common::grid::Grid;
+use common::{Error, Part, part_params};
+
+use crate::{Chessboard, Pasture, Quest10, extract_input_from_file};
+
+/// Pixels per cell side in SVG and PPM frames.
+const CELL_SIZE: usize = 8;
+/// Seconds each round stays on screen in the animated SVG.
+const FRAME_SECONDS: f64 = 0.5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceFormat {
+    Ascii,
+    Svg,
+    Ppm,
+}
+
+impl FromStr for TraceFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ascii" => Ok(TraceFormat::Ascii),
+            "svg" => Ok(TraceFormat::Svg),
+            "ppm" => Ok(TraceFormat::Ppm),
+            _ => Err(Error::param(
+                "trace",
+                format!("expected ascii, svg or ppm, got \"{}\"", s),
+            )),
+        }
+    }
+}
+
+/// What a cell shows in a frame. When several apply, the first one in this order wins.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Eaten,
+    Sheep,
+    Dragon,
+    Hideout,
+    Empty,
+}
+
+impl Cell {
+    fn color(self) -> [u8; 3] {
+        match self {
+            Cell::Eaten => [240, 190, 40],
+            Cell::Sheep => [250, 250, 250],
+            Cell::Dragon => [200, 50, 40],
+            Cell::Hideout => [100, 80, 60],
+            Cell::Empty => [170, 200, 130],
+        }
+    }
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbol = match self {
+            Cell::Eaten => 'x',
+            Cell::Sheep => 'S',
+            Cell::Dragon => 'D',
+            Cell::Hideout => '#',
+            Cell::Empty => '.',
+        };
+        write!(f, "{}", symbol)
+    }
+}
+
+/// Snapshot of a [`Pasture`] at the end of a round.
+pub struct Frame {
+    pub round: usize,
+    pub eaten_total: usize,
+    pub cells: Grid<Cell>,
+}
+
+impl Frame {
+    pub fn of(pasture: &Pasture) -> Self {
+        let mut cells = Grid::filled(pasture.sheep.rows(), pasture.sheep.columns(), Cell::Empty);
+        let layers = [
+            (pasture.hideouts(), Cell::Hideout),
+            (&pasture.dragons, Cell::Dragon),
+            (&pasture.sheep, Cell::Sheep),
+            (&pasture.eaten, Cell::Eaten),
+        ];
+        for (bitboard, cell) in layers {
+            for position in bitboard.iter() {
+                cells[position] = cell;
+            }
+        }
+        Frame {
+            round: pasture.round,
+            eaten_total: pasture.eaten_total,
+            cells,
+        }
+    }
+
+    fn title(&self) -> String {
+        format!("round {}: {} eaten", self.round, self.eaten_total)
+    }
+}
+
+/// Frames for the starting position and each of the `rounds` rounds of part 2.
+pub fn simulate(chessboard: &Chessboard, rounds: usize) -> Result<Vec<Frame>, Error> {
+    let mut pasture = Pasture::new(chessboard)?;
+    let mut frames = vec![Frame::of(&pasture)];
+    for _ in 1..=rounds {
+        pasture.next_round();
+        frames.push(Frame::of(&pasture));
+    }
+    Ok(frames)
+}
+
+pub fn render_ascii(frames: &[Frame]) -> String {
+    let mut result = String::from("S sheep, x eaten, D dragon reach, # hideout\n");
+    for frame in frames {
+        result += &format!("\n{}\n{}", frame.title(), frame.cells);
+    }
+    result
+}
+
+/// One SVG whose groups are shown in turn, one round every [`FRAME_SECONDS`], in a loop.
+pub fn render_svg(frames: &[Frame]) -> String {
+    let Some(first) = frames.first() else {
+        return String::new();
+    };
+    let width = first.cells.columns() * CELL_SIZE;
+    let height = first.cells.rows() * CELL_SIZE + 2 * CELL_SIZE;
+    let duration = frames.len() as f64 * FRAME_SECONDS;
+    let mut result = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" font-family=\"monospace\" font-size=\"{}\">\n",
+        width,
+        height,
+        CELL_SIZE + 2
+    );
+    for (index, frame) in frames.iter().enumerate() {
+        let shown_from = index as f64 / frames.len() as f64;
+        let shown_until = (index + 1) as f64 / frames.len() as f64;
+        let last = frames.len() - 1;
+        let visibility = match index {
+            _ if last == 0 => None,
+            0 => Some(("visible;hidden", format!("0;{}", shown_until))),
+            _ if index == last => Some(("hidden;visible", format!("0;{}", shown_from))),
+            _ => Some((
+                "hidden;visible;hidden",
+                format!("0;{};{}", shown_from, shown_until),
+            )),
+        };
+        result += "<g>\n";
+        if let Some((values, key_times)) = visibility {
+            result += &format!(
+                "<animate attributeName=\"visibility\" values=\"{}\" keyTimes=\"{}\" dur=\"{}s\" calcMode=\"discrete\" repeatCount=\"indefinite\"/>\n",
+                values, key_times, duration
+            );
+        }
+        result += &format!(
+            "<text x=\"0\" y=\"{}\">{}</text>\n",
+            CELL_SIZE + CELL_SIZE / 2,
+            frame.title()
+        );
+        for ((row, column), cell) in frame.cells.iter() {
+            let [red, green, blue] = cell.color();
+            result += &format!(
+                "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"#{:02x}{:02x}{:02x}\"/>\n",
+                column * CELL_SIZE,
+                (row + 2) * CELL_SIZE,
+                CELL_SIZE,
+                CELL_SIZE,
+                red,
+                green,
+                blue
+            );
+        }
+        result += "</g>\n";
+    }
+    result + "</svg>\n"
+}
+
+/// Binary (P6) PPM image of a single frame.
+pub fn render_ppm(frame: &Frame) -> Vec<u8> {
+    let (rows, columns) = (frame.cells.rows(), frame.cells.columns());
+    let mut result =
+        format!("P6\n{} {}\n255\n", columns * CELL_SIZE, rows * CELL_SIZE).into_bytes();
+    for row in 0..rows * CELL_SIZE {
+        for cell in frame.cells.row(row / CELL_SIZE) {
+            for _ in 0..CELL_SIZE {
+                result.extend_from_slice(&cell.color());
+            }
+        }
+    }
+    result
+}
+
+/// Solves `part` like [`common::solve`] while writing a trace of the part 2 simulation,
+/// either to `out` or to stdout. PPM frames go to `out` as a directory of `round_NNN.ppm`
+/// files.
+pub fn trace(
+    file_path: &str,
+    part: Part,
+    params: &[(String, String)],
+    format: &str,
+    out: Option<&Path>,
+) -> Result<String, Error> {
+    let format = format.parse::<TraceFormat>()?;
+    if part != Part::Two {
+        return Err(Error::param("trace", "only part 2 can be traced"));
+    }
+    let part_params = part_params::<Quest10>(part, params)?;
+    let chessboard = extract_input_from_file(file_path)?;
+    let frames = simulate(&chessboard, part_params.rounds)?;
+
+    match (format, out) {
+        (TraceFormat::Ppm, None) => {
+            return Err(Error::param(
+                "trace-out",
+                "ppm frames need an output directory",
+            ));
+        }
+        (TraceFormat::Ppm, Some(out)) => {
+            fs::create_dir_all(out).map_err(|e| Error::io(out, e))?;
+            for frame in &frames {
+                let path = out.join(format!("round_{:03}.ppm", frame.round));
+                fs::write(&path, render_ppm(frame)).map_err(|e| Error::io(&path, e))?;
+            }
+        }
+        (TraceFormat::Ascii | TraceFormat::Svg, out) => {
+            let rendered = match format {
+                TraceFormat::Svg => render_svg(&frames),
+                _ => render_ascii(&frames),
+            };
+            match out {
+                Some(out) => fs::write(out, rendered).map_err(|e| Error::io(out, e))?,
+                None => io::stdout()
+                    .write_all(rendered.as_bytes())
+                    .map_err(|e| Error::io("stdout", e))?,
+            }
+        }
+    }
+
+    let answer = frames.last().map_or(0, |frame| frame.eaten_total);
+    Ok(answer.to_string())
+}
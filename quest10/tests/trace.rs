@@ -0,0 +1,73 @@
+use common::Input;
+use quest10::{
+    parse_input,
+    trace::{Frame, render_ascii, render_ppm, render_svg, simulate},
+};
+
+const BOARD: &str = "S.S.\n..#.\nD...";
+
+fn frames(rounds: usize) -> Vec<Frame> {
+    simulate(&parse_input(&Input::from(BOARD)).unwrap(), rounds).unwrap()
+}
+
+#[test]
+fn ascii_shows_every_round() {
+    assert_eq!(
+        render_ascii(&frames(2)),
+        "S sheep, x eaten, D dragon reach, # hideout\n\
+         \n\
+         round 0: 0 eaten\n\
+         S.S.\n\
+         ..#.\n\
+         D...\n\
+         \n\
+         round 1: 0 eaten\n\
+         .D..\n\
+         S.S.\n\
+         ....\n\
+         \n\
+         round 2: 2 eaten\n\
+         D...\n\
+         ..#D\n\
+         x.x.\n"
+    );
+}
+
+#[test]
+fn ppm_is_a_p6_image_of_the_frame() {
+    let frames = frames(1);
+    let ppm = render_ppm(&frames[0]);
+    let header = b"P6\n32 24\n255\n";
+    assert!(ppm.starts_with(header));
+    assert_eq!(ppm.len(), header.len() + 32 * 24 * 3);
+
+    // The top left cell holds a sheep and the bottom left one the dragon.
+    let pixel = |x: usize, y: usize| {
+        let start = header.len() + (y * 32 + x) * 3;
+        ppm[start..start + 3].to_vec()
+    };
+    assert_eq!(pixel(0, 0), [250, 250, 250]);
+    assert_eq!(pixel(7, 7), [250, 250, 250]);
+    assert_eq!(pixel(8, 0), [170, 200, 130]);
+    assert_eq!(pixel(0, 23), [200, 50, 40]);
+}
+
+#[test]
+fn svg_has_a_group_of_cells_per_frame() {
+    let svg = render_svg(&frames(2));
+    assert!(
+        svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"32\" height=\"40\""),
+        "{}",
+        svg
+    );
+    assert!(svg.ends_with("</g>\n</svg>\n"));
+    assert_eq!(svg.matches("<g>").count(), 3);
+    assert_eq!(svg.matches("<animate ").count(), 3);
+    assert_eq!(svg.matches("<rect ").count(), 3 * 12);
+    assert!(svg.contains("<text x=\"0\" y=\"12\">round 2: 2 eaten</text>"));
+
+    let single = render_svg(&frames(0));
+    assert_eq!(single.matches("<g>").count(), 1);
+    assert_eq!(single.matches("<animate ").count(), 0);
+    assert_eq!(render_svg(&[]), "");
+}
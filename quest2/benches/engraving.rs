@@ -0,0 +1,121 @@
+//! Part 3 engraving on one thread against all available threads, against iterating one point
+//! at a time, and against the hand-written loop the configurable recurrence replaced:
+//! `cargo bench -p quest2`.
+//!
+//! Fails if one thread of lanes is more than [`MAX_SLOWDOWN`] times slower than the
+//! hand-written loop or the scalar loop.
+
+use std::{
+    num::NonZeroUsize,
+    thread,
+    time::{Duration, Instant},
+};
+
+use quest2::{ComplexNumber, Engraver, recurrence::Recurrence};
+
+const RUNS: usize = 5;
+
+/// Room for timing noise; evaluating the recurrence as a tree was about 6 times slower.
+const MAX_SLOWDOWN: f64 = 1.5;
+
+fn fastest_run(count: impl Fn() -> Option<usize>) -> Duration {
+    (0..RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            let engraved = count();
+            let elapsed = start.elapsed();
+            assert_eq!(engraved, Some(406954));
+            elapsed
+        })
+        .min()
+        .expect("at least one run")
+}
+
+/// The loop the lane batches replaced: every point iterated on its own until it escapes.
+fn scalar_count(engraver: &Engraver, corner: &ComplexNumber) -> Option<usize> {
+    let recurrence = engraver.recurrence.compile();
+    let mut values = Vec::new();
+    let lower = -engraver.bound;
+    let mut engraved = 0;
+    for y in (corner.y..=corner.y + engraver.size).step_by(engraver.step as usize) {
+        for x in (corner.x..=corner.x + engraver.size).step_by(engraver.step as usize) {
+            let point = ComplexNumber { x, y };
+            let mut current = point;
+            let mut escaped = false;
+            for _ in 0..engraver.iterations {
+                current = recurrence.next(&current, &point, &mut values)?;
+                if !current.within(&lower, &engraver.bound) {
+                    escaped = true;
+                    break;
+                }
+            }
+            engraved += usize::from(!escaped);
+        }
+    }
+    Some(engraved)
+}
+
+/// Part 3 with the puzzle's numbers written into the loop, as before the recurrence and the
+/// bounds could be configured.
+fn hand_written_count(corner: &ComplexNumber) -> Option<usize> {
+    let mut engraved = 0;
+    for y in corner.y..=corner.y + 1000 {
+        for x in corner.x..=corner.x + 1000 {
+            let (mut current_x, mut current_y) = (x, y);
+            let mut escaped = false;
+            for _ in 0..99 {
+                let square_x = current_x * current_x - current_y * current_y;
+                let square_y = 2 * current_x * current_y;
+                current_x = square_x / 100000 + x;
+                current_y = square_y / 100000 + y;
+                if !(-1000000..=1000000).contains(&current_x)
+                    || !(-1000000..=1000000).contains(&current_y)
+                {
+                    escaped = true;
+                    break;
+                }
+            }
+            engraved += usize::from(!escaped);
+        }
+    }
+    Some(engraved)
+}
+
+fn check(name: &str, lanes: Duration, reference: Duration) {
+    let slowdown = lanes.as_secs_f64() / reference.as_secs_f64();
+    println!("lanes vs {}: {:.2}x the time", name, slowdown);
+    assert!(
+        slowdown <= MAX_SLOWDOWN,
+        "one thread of lanes is {:.2}x slower than the {} loop",
+        slowdown,
+        name
+    );
+}
+
+fn main() {
+    let corner = ComplexNumber::new(35300, -64910);
+    let engraver = Engraver::new(1);
+    // The same recurrence in a shape the puzzle fast path doesn't match.
+    let ops_engraver = Engraver {
+        recurrence: Recurrence::parse("A+R*R/[100000,100000]").unwrap(),
+        ..Engraver::new(1)
+    };
+    let threads = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+
+    let hand_written = fastest_run(|| hand_written_count(&corner));
+    let scalar = fastest_run(|| scalar_count(&engraver, &corner));
+    let sequential = fastest_run(|| engraver.count_with_threads(&corner, 1));
+    let ops = fastest_run(|| ops_engraver.count_with_threads(&corner, 1));
+    let parallel = fastest_run(|| engraver.count_with_threads(&corner, threads));
+    println!("part 3, hand-written: {:?}", hand_written);
+    println!("part 3, scalar:       {:?}", scalar);
+    println!("part 3, 1 thread:     {:?}", sequential);
+    println!("part 3, 1 thread, op list: {:?}", ops);
+    println!("part 3, {} threads:    {:?}", threads, parallel);
+    println!(
+        "speedup: {:.2}x",
+        sequential.as_secs_f64() / parallel.as_secs_f64()
+    );
+    check("hand-written", sequential, hand_written);
+    check("scalar", sequential, scalar);
+}
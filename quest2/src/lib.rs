@@ -0,0 +1,732 @@
+use common::grid::Grid;
+use common::{Error, Input, Params, Part, Solution, parse_param};
+use num_integer::Integer;
+use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Signed, Zero};
+use std::num::NonZeroUsize;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, Sub};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::{array, fmt, str::FromStr, thread};
+
+pub use num_bigint::BigInt;
+
+pub mod reciprocal;
+pub mod recurrence;
+pub mod render;
+
+use reciprocal::Reciprocal;
+use recurrence::{Compiled, Recurrence};
+
+/// Integer type a [`ComplexNumber`] can be built from: `i32`, `i64`, `i128` or [`BigInt`].
+pub trait Component:
+    Clone
+    + PartialOrd
+    + Zero
+    + From<i32>
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + CheckedAdd
+    + CheckedSub
+    + CheckedMul
+    + CheckedDiv
+    + fmt::Display
+    + FromStr<Err: fmt::Display>
+{
+    /// A divisor prepared by [`Component::divisor`].
+    type Divisor: Clone + fmt::Debug + Send + Sync;
+
+    /// Prepares dividing by `divisor` over and over, `None` if it is zero. `i32` and `i64`
+    /// turn it into a [`Reciprocal`], so [`Component::checked_div_by`] multiplies instead of
+    /// dividing.
+    fn divisor(divisor: &Self) -> Option<Self::Divisor>;
+
+    /// `self / divisor` truncated toward zero, like [`CheckedDiv`]. `None` on overflow.
+    fn checked_div_by(&self, divisor: &Self::Divisor) -> Option<Self>;
+}
+
+macro_rules! component_with_reciprocal {
+    ($($signed:ty => $unsigned:ty),*) => {$(
+        impl Component for $signed {
+            type Divisor = Reciprocal<$signed, $unsigned>;
+
+            fn divisor(divisor: &Self) -> Option<Self::Divisor> {
+                Reciprocal::<$signed, $unsigned>::new(*divisor)
+            }
+
+            fn checked_div_by(&self, divisor: &Self::Divisor) -> Option<Self> {
+                divisor.checked_div(*self)
+            }
+        }
+    )*};
+}
+
+macro_rules! component_with_division {
+    ($($component:ty),*) => {$(
+        impl Component for $component {
+            type Divisor = Self;
+
+            fn divisor(divisor: &Self) -> Option<Self::Divisor> {
+                (!divisor.is_zero()).then(|| divisor.clone())
+            }
+
+            fn checked_div_by(&self, divisor: &Self::Divisor) -> Option<Self> {
+                self.checked_div(divisor)
+            }
+        }
+    )*};
+}
+
+component_with_reciprocal!(i32 => u32, i64 => u64);
+component_with_division!(i128, BigInt);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ComplexNumber<T = i64> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Component> ComplexNumber<T> {
+    pub fn new(x: i32, y: i32) -> Self {
+        ComplexNumber {
+            x: T::from(x),
+            y: T::from(y),
+        }
+    }
+
+    pub fn from_file(path: &str) -> Result<Self, Error> {
+        Self::from_input(&Input::open(path)?)
+    }
+
+    /// Reads the single `A=[x,y]` line of a puzzle input.
+    pub fn from_input(input: &Input) -> Result<Self, Error> {
+        let number = input.trimmed();
+        let number = number.strip_prefix("A=").unwrap_or(number);
+        parse_complex_number(input.text(), number).map_err(|e| input.with_path(e))
+    }
+
+    /// `None` if either component overflows.
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(ComplexNumber {
+            x: self.x.checked_add(&other.x)?,
+            y: self.y.checked_add(&other.y)?,
+        })
+    }
+
+    /// `None` if either component overflows.
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        Some(ComplexNumber {
+            x: self.x.checked_sub(&other.x)?,
+            y: self.y.checked_sub(&other.y)?,
+        })
+    }
+
+    /// `None` if any partial product or sum overflows.
+    pub fn checked_mul(&self, other: &Self) -> Option<Self> {
+        Some(ComplexNumber {
+            x: self
+                .x
+                .checked_mul(&other.x)?
+                .checked_sub(&self.y.checked_mul(&other.y)?)?,
+            y: self
+                .x
+                .checked_mul(&other.y)?
+                .checked_add(&self.y.checked_mul(&other.x)?)?,
+        })
+    }
+
+    /// `self^exponent` by repeated squaring, so huge exponents of `0`, `1` or `i` are still
+    /// quick. `None` if a power it needs overflows.
+    pub fn checked_pow(&self, mut exponent: u32) -> Option<Self> {
+        let mut result = ComplexNumber::new(1, 0);
+        let mut power = self.clone();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result.checked_mul(&power)?;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                power = power.checked_mul(&power)?;
+            }
+        }
+        Some(result)
+    }
+
+    /// [`ComplexNumber::div_components`] by divisors prepared with [`Component::divisor`],
+    /// `None` on overflow.
+    pub fn checked_div_by(&self, x: &T::Divisor, y: &T::Divisor) -> Option<Self> {
+        Some(ComplexNumber {
+            x: self.x.checked_div_by(x)?,
+            y: self.y.checked_div_by(y)?,
+        })
+    }
+
+    /// [`ComplexNumber::div_components`], `None` on overflow or division by zero.
+    pub fn checked_div_components(&self, other: &Self) -> Option<Self> {
+        Some(ComplexNumber {
+            x: self.x.checked_div(&other.x)?,
+            y: self.y.checked_div(&other.y)?,
+        })
+    }
+
+    /// Whether both components are within `lower..=upper`.
+    pub fn within(&self, lower: &T, upper: &T) -> bool {
+        lower <= &self.x && &self.x <= upper && lower <= &self.y && &self.y <= upper
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for ComplexNumber<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{},{}]", self.x, self.y)
+    }
+}
+
+impl<T: Component> FromStr for ComplexNumber<T> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_complex_number(s, s)
+    }
+}
+
+/// Parses `s`, which is a slice of `source`, so errors can point into the whole input.
+pub(crate) fn parse_complex_number<T: Component>(
+    source: &str,
+    s: &str,
+) -> Result<ComplexNumber<T>, Error> {
+    let s = s.trim();
+
+    let s = s
+        .strip_prefix('[')
+        .and_then(|s| s.strip_suffix(']'))
+        .unwrap_or(s)
+        .trim();
+
+    let s = s
+        .strip_prefix('(')
+        .and_then(|s| s.strip_suffix(')'))
+        .unwrap_or(s)
+        .trim();
+
+    let (x_str, y_str) = s
+        .split_once(',')
+        .ok_or_else(|| Error::format(source, s, "expected format: x,y, [x,y] or (x,y)"))?;
+
+    let x_str = x_str.trim();
+    let y_str = y_str.trim();
+    let x = x_str
+        .parse::<T>()
+        .map_err(|e| Error::invalid_value(source, x_str, format!("invalid x value: {}", e)))?;
+    let y = y_str
+        .parse::<T>()
+        .map_err(|e| Error::invalid_value(source, y_str, format!("invalid y value: {}", e)))?;
+
+    Ok(ComplexNumber { x, y })
+}
+
+impl<T: Add<Output = T>> Add for ComplexNumber<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        ComplexNumber {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+
+impl<T> Mul for ComplexNumber<T>
+where
+    T: Clone + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        ComplexNumber {
+            x: self.x.clone() * other.x.clone() - self.y.clone() * other.y.clone(),
+            y: self.x * other.y + self.y * other.x,
+        }
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for ComplexNumber<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        ComplexNumber {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+}
+
+impl<T: Div<Output = T>> ComplexNumber<T> {
+    /// The puzzle's division: `x / other.x` and `y / other.y`, each truncated toward zero.
+    /// This is not complex division, see [`ComplexNumber::div_rem`] for that.
+    pub fn div_components(self, other: Self) -> Self {
+        ComplexNumber {
+            x: self.x / other.x,
+            y: self.y / other.y,
+        }
+    }
+}
+
+/// Gaussian integer arithmetic.
+impl<T: Integer + Signed + Clone> ComplexNumber<T> {
+    pub fn conj(&self) -> Self {
+        ComplexNumber {
+            x: self.x.clone(),
+            y: -self.y.clone(),
+        }
+    }
+
+    /// `x² + y²`, the square of the absolute value.
+    pub fn norm(&self) -> T {
+        self.x.clone() * self.x.clone() + self.y.clone() * self.y.clone()
+    }
+
+    /// Quotient rounded to the nearest Gaussian integer and the matching remainder, so that
+    /// `self == quotient * other + remainder` and `remainder.norm() <= other.norm() / 2`.
+    /// `None` if `other` is zero.
+    pub fn div_rem(&self, other: &Self) -> Option<(Self, Self)> {
+        let norm = other.norm();
+        if norm.is_zero() {
+            return None;
+        }
+        let numerator = self.clone() * other.conj();
+        let two = T::one() + T::one();
+        let round = |component: T| {
+            (component * two.clone() + norm.clone()).div_floor(&(norm.clone() * two.clone()))
+        };
+        let quotient = ComplexNumber {
+            x: round(numerator.x),
+            y: round(numerator.y),
+        };
+        let remainder = self.clone() - quotient.clone() * other.clone();
+        Some((quotient, remainder))
+    }
+
+    /// Greatest common divisor, normalised to the associate with `x > 0` and `y >= 0`
+    /// (zero if both are zero).
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while let Some((_, remainder)) = a.div_rem(&b) {
+            a = b;
+            b = remainder;
+        }
+        // Multiplying by i turns (x, y) into (-y, x); at most three turns reach the quadrant.
+        while !a.norm().is_zero() && (!a.x.is_positive() || a.y.is_negative()) {
+            a = ComplexNumber { x: -a.y, y: a.x };
+        }
+        a
+    }
+}
+
+impl<T: Integer + Signed + Clone> Div for ComplexNumber<T> {
+    type Output = Self;
+
+    /// Gaussian quotient, see [`ComplexNumber::div_rem`]. Panics if `other` is zero.
+    fn div(self, other: Self) -> Self {
+        self.div_rem(&other).expect("Gaussian division by zero").0
+    }
+}
+
+impl<T: Integer + Signed + Clone> Rem for ComplexNumber<T> {
+    type Output = Self;
+
+    /// Panics if `other` is zero.
+    fn rem(self, other: Self) -> Self {
+        self.div_rem(&other).expect("Gaussian division by zero").1
+    }
+}
+
+impl<T: Clone + Add<Output = T>> AddAssign for ComplexNumber<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = self.clone() + rhs;
+    }
+}
+
+impl<T> MulAssign for ComplexNumber<T>
+where
+    T: Clone + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.clone() * rhs;
+    }
+}
+
+impl<T: Integer + Signed + Clone> DivAssign for ComplexNumber<T> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = self.clone() / rhs;
+    }
+}
+
+/// `None` if the iteration overflows `T`.
+pub fn part_1<T: Component>(complex_number_a: ComplexNumber<T>) -> Option<ComplexNumber<T>> {
+    Recurrence::puzzle(10).iterate(&complex_number_a, 3)
+}
+
+/// Points an engraving worker iterates side by side.
+pub const LANES: usize = 16;
+
+/// How [`Engraver::escape_batch`] gets the next value of a lane.
+enum Step<'a, T: Component> {
+    /// The puzzle's recurrence on a grid [`Engraver::puzzle_peak`] shows it can't overflow
+    /// on, so without overflow checks.
+    Unchecked {
+        x: &'a T::Divisor,
+        y: &'a T::Divisor,
+    },
+    Checked(&'a Compiled<T>),
+}
+
+/// Settings of the engraving check shared by parts 2 and 3, which only differ in `step`.
+#[derive(Debug, Clone)]
+pub struct Engraver<T = i64> {
+    /// Side of the engraved square, starting at the corner.
+    pub size: T,
+    /// Distance between two checked points.
+    pub step: T,
+    pub recurrence: Recurrence<T>,
+    /// A point stops being engraved once a component leaves `-bound..=bound`.
+    pub bound: T,
+    pub iterations: usize,
+}
+
+impl<T: Component> Engraver<T> {
+    pub fn new(step: i32) -> Self {
+        Engraver {
+            size: T::from(1000),
+            step: T::from(step),
+            recurrence: Recurrence::puzzle(100000),
+            bound: T::from(1000000),
+            iterations: 99,
+        }
+    }
+
+    /// `None` if the iteration overflows `T`, see [`Engraver::escape_lanes`].
+    pub fn is_engraved(&self, point: &ComplexNumber<T>) -> Option<bool> {
+        let escapes = self.escape_lanes(std::slice::from_ref(&point.x), &point.y)?;
+        Some(escapes == [None])
+    }
+
+    /// For every `x` in `xs`, the iteration at which `(x, y)` left the bounds, or `None` if it
+    /// is engraved. The points are iterated in lockstep, one iteration over all lanes at a
+    /// time, until every lane has escaped.
+    ///
+    /// `None` if the recurrence overflows `T` or divides by zero before a point escapes: the
+    /// puzzle's arithmetic is unbounded, so the point may or may not have stayed in bounds.
+    pub fn escape_lanes(&self, xs: &[T], y: &T) -> Option<Vec<Option<usize>>> {
+        let recurrence = self.recurrence.compile();
+        let step = self.step(&recurrence, xs, std::slice::from_ref(y));
+        self.escape_row(&step, xs, y)
+    }
+
+    /// How to evaluate `recurrence` on the grid of `xs` and `ys`.
+    fn step<'a>(&self, recurrence: &'a Compiled<T>, xs: &[T], ys: &[T]) -> Step<'a, T> {
+        match recurrence {
+            Compiled::Puzzle { x, y } if self.puzzle_peak(xs, ys).is_some() => {
+                Step::Unchecked { x, y }
+            }
+            _ => Step::Checked(recurrence),
+        }
+    }
+
+    /// The largest component `R*R/divisor+A` can reach for a point `A` of the grid, `None`
+    /// if that overflows `T`. `R` starts out as `A` and stops being iterated once it leaves
+    /// the bounds, so its components are at most `m`, the larger of `bound` and the largest
+    /// `|A|`, and the next value's at most `2m² + |A|`.
+    fn puzzle_peak(&self, xs: &[T], ys: &[T]) -> Option<T> {
+        let magnitude = |value: &T| {
+            if value < &T::zero() {
+                T::zero().checked_sub(value)
+            } else {
+                Some(value.clone())
+            }
+        };
+        let max = |a: T, b: T| if a < b { b } else { a };
+        let mut point = T::zero();
+        for component in xs.iter().chain(ys) {
+            point = max(point, magnitude(component)?);
+        }
+        let value = max(point.clone(), self.bound.clone());
+        let square = value.checked_mul(&value)?;
+        square.checked_add(&square)?.checked_add(&point)
+    }
+
+    /// [`Engraver::escape_lanes`] with the recurrence prepared by the caller, so a whole grid
+    /// compiles it once.
+    fn escape_row(&self, step: &Step<T>, xs: &[T], y: &T) -> Option<Vec<Option<usize>>> {
+        let mut values = Vec::new();
+        let mut escapes = Vec::with_capacity(xs.len());
+        for batch in xs.chunks(LANES) {
+            let batch_escapes = self.escape_batch(step, &mut values, batch, y)?;
+            escapes.extend_from_slice(&batch_escapes[..batch.len()]);
+        }
+        Some(escapes)
+    }
+
+    /// [`Engraver::escape_lanes`] of at most [`LANES`] points. A lane's value lives in the
+    /// `x` and `y` arrays for as long as its `active` flag is set; lanes past the end of `xs`
+    /// start out inactive.
+    fn escape_batch(
+        &self,
+        step: &Step<T>,
+        values: &mut Vec<ComplexNumber<T>>,
+        xs: &[T],
+        y: &T,
+    ) -> Option<[Option<usize>; LANES]> {
+        let Some(lower) = T::zero().checked_sub(&self.bound) else {
+            return Some([Some(0); LANES]);
+        };
+        let point_xs: [T; LANES] =
+            array::from_fn(|lane| xs.get(lane).cloned().unwrap_or_else(T::zero));
+        let mut current_xs = point_xs.clone();
+        let mut current_ys: [T; LANES] = array::from_fn(|_| y.clone());
+        let mut active: [bool; LANES] = array::from_fn(|lane| lane < xs.len());
+        let mut escapes = [None; LANES];
+        for iteration in 0..self.iterations {
+            for lane in 0..LANES {
+                if !active[lane] {
+                    continue;
+                }
+                let current = ComplexNumber {
+                    x: current_xs[lane].clone(),
+                    y: current_ys[lane].clone(),
+                };
+                let point = ComplexNumber {
+                    x: point_xs[lane].clone(),
+                    y: y.clone(),
+                };
+                let next = match step {
+                    Step::Unchecked {
+                        x: divisor_x,
+                        y: divisor_y,
+                    } => (current.clone() * current).checked_div_by(divisor_x, divisor_y)? + point,
+                    Step::Checked(recurrence) => recurrence.next(&current, &point, values)?,
+                };
+                if next.within(&lower, &self.bound) {
+                    current_xs[lane] = next.x;
+                    current_ys[lane] = next.y;
+                } else {
+                    active[lane] = false;
+                    escapes[lane] = Some(iteration);
+                }
+            }
+            if !active.contains(&true) {
+                break;
+            }
+        }
+        Some(escapes)
+    }
+
+    /// `start`, `start + step`, ... up to `start + size`. Empty if `size` is negative or
+    /// `step` isn't positive.
+    fn axis(&self, start: &T) -> Option<Vec<T>> {
+        let end = start.checked_add(&self.size)?;
+        let mut result = Vec::new();
+        if self.step <= T::zero() {
+            return Some(result);
+        }
+        let mut current = start.clone();
+        while current <= end {
+            let next = current.checked_add(&self.step);
+            result.push(current);
+            match next {
+                Some(next) => current = next,
+                None => break,
+            }
+        }
+        Some(result)
+    }
+}
+
+impl<T: Component + Send + Sync> Engraver<T> {
+    /// Engraved points of the grid spanning `corner` to `corner + [size,size]`, on as many
+    /// threads as the machine has. `None` if the grid itself or the iteration of one of its
+    /// points overflows `T`.
+    pub fn count(&self, corner: &ComplexNumber<T>) -> Option<usize> {
+        let threads = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+        self.count_with_threads(corner, threads)
+    }
+
+    pub fn count_with_threads(&self, corner: &ComplexNumber<T>, threads: usize) -> Option<usize> {
+        let rows = self.map_rows(corner, threads, |escapes| {
+            escapes.iter().filter(|escape| escape.is_none()).count()
+        })?;
+        Some(rows.into_iter().sum())
+    }
+
+    /// Escape iteration of every point of the grid, see [`Engraver::escape_lanes`]. Row 0 is
+    /// the corner's `y`, column 0 its `x`.
+    pub fn escape_grid(&self, corner: &ComplexNumber<T>) -> Option<Grid<Option<usize>>> {
+        let threads = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+        let rows = self.map_rows(corner, threads, <[_]>::to_vec)?;
+        let columns = rows.first().map_or(0, Vec::len);
+        let mut grid = Grid::filled(rows.len(), columns, None);
+        for (row, escapes) in rows.into_iter().enumerate() {
+            for (column, escape) in escapes.into_iter().enumerate() {
+                grid[(row, column)] = escape;
+            }
+        }
+        Some(grid)
+    }
+
+    /// Runs `f` on the escape iterations of every row of the grid. Threads take rows one at a
+    /// time and evaluate each row in batches of [`LANES`] points.
+    fn map_rows<R: Send>(
+        &self,
+        corner: &ComplexNumber<T>,
+        threads: usize,
+        f: impl Fn(&[Option<usize>]) -> R + Sync,
+    ) -> Option<Vec<R>> {
+        let xs = self.axis(&corner.x)?;
+        let ys = self.axis(&corner.y)?;
+        if xs.is_empty() || ys.is_empty() {
+            return Some(Vec::new());
+        }
+        let recurrence = self.recurrence.compile();
+        let step = self.step(&recurrence, &xs, &ys);
+        let next_row = AtomicUsize::new(0);
+        let workers = thread::scope(|scope| {
+            let workers = (0..threads.clamp(1, ys.len()))
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut done = Vec::new();
+                        loop {
+                            let row = next_row.fetch_add(1, Ordering::Relaxed);
+                            let Some(y) = ys.get(row) else {
+                                break Some(done);
+                            };
+                            let Some(escapes) = self.escape_row(&step, &xs, y) else {
+                                // The count is lost anyway, so the other workers can stop too.
+                                next_row.store(ys.len(), Ordering::Relaxed);
+                                break None;
+                            };
+                            done.push((row, f(&escapes)));
+                        }
+                    })
+                })
+                .collect::<Vec<_>>();
+            workers
+                .into_iter()
+                .map(|worker| worker.join().expect("engraving worker panicked"))
+                .collect::<Option<Vec<_>>>()
+        })?;
+        let mut rows = workers.into_iter().flatten().collect::<Vec<_>>();
+        rows.sort_unstable_by_key(|(row, _)| *row);
+        Some(rows.into_iter().map(|(_, result)| result).collect())
+    }
+}
+
+pub fn part_2<T: Component + Send + Sync>(corner: ComplexNumber<T>) -> Option<usize> {
+    Engraver::new(10).count(&corner)
+}
+
+pub fn part_3<T: Component + Send + Sync>(corner: ComplexNumber<T>) -> Option<usize> {
+    Engraver::new(1).count(&corner)
+}
+
+/// Part 1 only uses `recurrence` and `iterations`.
+pub struct Quest2Params {
+    pub recurrence: Recurrence,
+    pub iterations: usize,
+    pub bound: i64,
+    pub step: i64,
+    pub size: i64,
+}
+
+impl Quest2Params {
+    pub fn engraver(&self) -> Engraver {
+        Engraver {
+            size: self.size,
+            step: self.step,
+            recurrence: self.recurrence.clone(),
+            bound: self.bound,
+            iterations: self.iterations,
+        }
+    }
+}
+
+impl Params for Quest2Params {
+    fn for_part(part: Part) -> Self {
+        let engraver = Engraver::new(if part == Part::Three { 1 } else { 10 });
+        match part {
+            Part::One => Quest2Params {
+                recurrence: Recurrence::puzzle(10),
+                iterations: 3,
+                ..Quest2Params::from(engraver)
+            },
+            Part::Two | Part::Three => Quest2Params::from(engraver),
+        }
+    }
+
+    fn set(&mut self, key: &str, value: &str) -> Result<(), Error> {
+        match key {
+            "recurrence" => self.recurrence = Recurrence::parse(value)?,
+            "iterations" => self.iterations = parse_param(key, value)?,
+            "bound" => self.bound = parse_at_least(key, value, 0)?,
+            "step" => self.step = parse_at_least(key, value, 1)?,
+            "size" => self.size = parse_at_least(key, value, 0)?,
+            _ => return Err(Error::param(key, "unknown param")),
+        }
+        Ok(())
+    }
+}
+
+fn parse_at_least(key: &str, value: &str, min: i64) -> Result<i64, Error> {
+    let parsed = parse_param(key, value)?;
+    if parsed < min {
+        return Err(Error::param(
+            key,
+            format!("expected at least {}, got {}", min, parsed),
+        ));
+    }
+    Ok(parsed)
+}
+
+impl From<Engraver> for Quest2Params {
+    fn from(engraver: Engraver) -> Self {
+        Quest2Params {
+            recurrence: engraver.recurrence,
+            iterations: engraver.iterations,
+            bound: engraver.bound,
+            step: engraver.step,
+            size: engraver.size,
+        }
+    }
+}
+
+pub struct Quest2;
+
+impl Solution for Quest2 {
+    type Input = ComplexNumber;
+    type Params = Quest2Params;
+    type Answer1 = ComplexNumber;
+    type Answer2 = usize;
+    type Answer3 = usize;
+
+    fn parse_input(input: &Input) -> Result<Self::Input, Error> {
+        ComplexNumber::from_input(input)
+    }
+
+    fn part1(input: &ComplexNumber, params: &Quest2Params) -> Result<ComplexNumber, Error> {
+        params
+            .recurrence
+            .iterate(input, params.iterations)
+            .ok_or_else(|| Error::unsolvable("part 1 overflowed i64 or divided by zero"))
+    }
+
+    fn part2(input: &ComplexNumber, params: &Quest2Params) -> Result<usize, Error> {
+        params
+            .engraver()
+            .count(input)
+            .ok_or_else(|| Error::unsolvable("part 2 overflowed i64 or divided by zero"))
+    }
+
+    fn part3(input: &ComplexNumber, params: &Quest2Params) -> Result<usize, Error> {
+        params
+            .engraver()
+            .count(input)
+            .ok_or_else(|| Error::unsolvable("part 3 overflowed i64 or divided by zero"))
+    }
+}
@@ -0,0 +1,55 @@
+//! Division of `i32` and `i64` by a divisor known ahead of time, as a multiplication and a
+//! shift: the engraving loop divides by the same constant a few hundred million times.
+
+/// `divisor` prepared for [`crate::Component::checked_div_by`].
+///
+/// `multiplier` is Granlund and Montgomery's: with `l = ceil(log2 |divisor|)` and `N` the
+/// bits of the signed type minus one, `multiplier = 2^(N + l) / |divisor| + 1`, so that
+/// `n / |divisor| == n * multiplier >> (N + l)` for `0 <= n < 2^N`. Doubling `n` first makes
+/// that the high half of an unsigned product, shifted right by `l`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reciprocal<T, U> {
+    divisor: T,
+    multiplier: U,
+    log: u32,
+}
+
+macro_rules! reciprocal {
+    ($signed:ty, $unsigned:ty, $wide:ty) => {
+        impl Reciprocal<$signed, $unsigned> {
+            /// `None` for zero.
+            pub fn new(divisor: $signed) -> Option<Self> {
+                if divisor == 0 {
+                    return None;
+                }
+                let magnitude = divisor.unsigned_abs();
+                let log = <$unsigned>::BITS - (magnitude - 1).leading_zeros();
+                let multiplier =
+                    ((1 as $wide) << (<$signed>::BITS - 1 + log)) / magnitude as $wide + 1;
+                Some(Reciprocal {
+                    divisor,
+                    multiplier: multiplier as $unsigned,
+                    log,
+                })
+            }
+
+            /// `n / divisor` truncated toward zero, `None` for `MIN / -1`.
+            pub fn checked_div(&self, n: $signed) -> Option<$signed> {
+                // |MIN| is the one magnitude past 2^N.
+                if n == <$signed>::MIN {
+                    return n.checked_div(self.divisor);
+                }
+                let doubled = (n.unsigned_abs() << 1) as $wide;
+                let high = (doubled * self.multiplier as $wide) >> <$unsigned>::BITS;
+                let quotient = (high as $unsigned >> self.log) as $signed;
+                // All ones if the signs differ, negating without a branch the sign of `n`
+                // would mispredict.
+                let sign = (n ^ self.divisor) >> (<$signed>::BITS - 1);
+                Some((quotient ^ sign) - sign)
+            }
+        }
+    };
+}
+
+reciprocal!(i32, u32, u64);
+reciprocal!(i64, u64, u128);
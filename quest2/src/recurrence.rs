@@ -0,0 +1,410 @@
+use std::{fmt, str::FromStr};
+
+use common::Error;
+
+use crate::{ComplexNumber, Component, parse_complex_number};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    Add,
+    Sub,
+    Mul,
+    /// The puzzle's component-wise division, see [`ComplexNumber::div_components`].
+    Div,
+}
+
+impl Operator {
+    fn symbol(self) -> char {
+        match self {
+            Operator::Add => '+',
+            Operator::Sub => '-',
+            Operator::Mul => '*',
+            Operator::Div => '/',
+        }
+    }
+
+    /// `None` on overflow or division by zero.
+    fn apply<T: Component>(
+        self,
+        lhs: &ComplexNumber<T>,
+        rhs: &ComplexNumber<T>,
+    ) -> Option<ComplexNumber<T>> {
+        match self {
+            Operator::Add => lhs.checked_add(rhs),
+            Operator::Sub => lhs.checked_sub(rhs),
+            Operator::Mul => lhs.checked_mul(rhs),
+            Operator::Div => lhs.checked_div_components(rhs),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr<T> {
+    /// `R`, the value of the previous iteration.
+    Current,
+    /// `A`, the point being iterated.
+    Point,
+    /// `[x,y]`.
+    Constant(ComplexNumber<T>),
+    Binary(Operator, Box<Expr<T>>, Box<Expr<T>>),
+    /// `e^n` for a literal `n`.
+    Power(Box<Expr<T>>, u32),
+}
+
+/// Where an [`Op`] reads an operand from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Operand<T> {
+    Current,
+    Point,
+    Constant(ComplexNumber<T>),
+    /// The result of the op at this index.
+    Value(usize),
+}
+
+/// One operation of a [`Compiled::Ops`] list.
+#[derive(Debug, Clone)]
+pub enum Op<T: Component> {
+    Binary(Operator, Operand<T>, Operand<T>),
+    /// Component-wise division by a constant, prepared with [`Component::divisor`].
+    DivideBy(Operand<T>, T::Divisor, T::Divisor),
+    Power(Operand<T>, u32),
+}
+
+/// A [`Recurrence`] flattened once, so iterating it doesn't walk the expression tree, see
+/// [`Recurrence::compile`].
+#[derive(Debug, Clone)]
+pub enum Compiled<T: Component> {
+    /// `R*R/[x,y]+A`, the shape of the puzzle's recurrence, in one go.
+    Puzzle { x: T::Divisor, y: T::Divisor },
+    /// The operations in evaluation order, each result stored at the op's index. `result` is
+    /// the last op's value, or the whole recurrence if it is a lone operand.
+    Ops { ops: Vec<Op<T>>, result: Operand<T> },
+}
+
+/// Next value of an iteration in terms of `R` and `A`, e.g. the puzzle's `R*R/[10,10]+A`.
+/// Supports `+ - * /` with the usual precedence, `^` with a literal exponent, parentheses
+/// and `[x,y]` constants.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Recurrence<T = i64> {
+    pub expr: Expr<T>,
+}
+
+impl<T: Component> Recurrence<T> {
+    /// `R*R/[divisor,divisor]+A`, the recurrence every part of the puzzle uses.
+    pub fn puzzle(divisor: i32) -> Self {
+        let square = Expr::Binary(
+            Operator::Mul,
+            Box::new(Expr::Current),
+            Box::new(Expr::Current),
+        );
+        let divided = Expr::Binary(
+            Operator::Div,
+            Box::new(square),
+            Box::new(Expr::Constant(ComplexNumber::new(divisor, divisor))),
+        );
+        Recurrence {
+            expr: Expr::Binary(Operator::Add, Box::new(divided), Box::new(Expr::Point)),
+        }
+    }
+
+    pub fn parse(source: &str) -> Result<Self, Error> {
+        let mut parser = Parser { source, offset: 0 };
+        let expr = parser.expr()?;
+        parser.skip_whitespace();
+        if parser.offset < source.len() {
+            return Err(Error::format(
+                source,
+                &source[parser.offset..],
+                "expected an operator or the end of the expression",
+            ));
+        }
+        Ok(Recurrence { expr })
+    }
+
+    /// The recurrence in the form the engraving loop evaluates: a fast path for the puzzle's
+    /// shape, a flat list of operations for anything else.
+    pub fn compile(&self) -> Compiled<T> {
+        if let Some(divisor) = self.puzzle_divisor()
+            && let (Some(x), Some(y)) = (T::divisor(&divisor.x), T::divisor(&divisor.y))
+        {
+            return Compiled::Puzzle { x, y };
+        }
+        let mut ops = Vec::new();
+        let result = lower(&self.expr, &mut ops);
+        Compiled::Ops { ops, result }
+    }
+
+    /// `divisor` if the recurrence is `R*R/divisor+A` for a constant `divisor`.
+    fn puzzle_divisor(&self) -> Option<&ComplexNumber<T>> {
+        let Expr::Binary(Operator::Add, quotient, point) = &self.expr else {
+            return None;
+        };
+        let Expr::Binary(Operator::Div, square, divisor) = quotient.as_ref() else {
+            return None;
+        };
+        let (Expr::Binary(Operator::Mul, lhs, rhs), Expr::Constant(divisor)) =
+            (square.as_ref(), divisor.as_ref())
+        else {
+            return None;
+        };
+        let is_square = **lhs == Expr::Current && **rhs == Expr::Current;
+        (is_square && **point == Expr::Point).then_some(divisor)
+    }
+
+    /// `None` on overflow or division by zero.
+    pub fn next(
+        &self,
+        current: &ComplexNumber<T>,
+        point: &ComplexNumber<T>,
+    ) -> Option<ComplexNumber<T>> {
+        evaluate(&self.expr, current, point)
+    }
+
+    /// Runs `iterations` steps starting from `R = [0,0]`.
+    pub fn iterate(&self, point: &ComplexNumber<T>, iterations: usize) -> Option<ComplexNumber<T>> {
+        let mut result = ComplexNumber::new(0, 0);
+        for _ in 0..iterations {
+            result = self.next(&result, point)?;
+        }
+        Some(result)
+    }
+}
+
+fn evaluate<T: Component>(
+    expr: &Expr<T>,
+    current: &ComplexNumber<T>,
+    point: &ComplexNumber<T>,
+) -> Option<ComplexNumber<T>> {
+    match expr {
+        Expr::Current => Some(current.clone()),
+        Expr::Point => Some(point.clone()),
+        Expr::Constant(constant) => Some(constant.clone()),
+        Expr::Binary(operator, lhs, rhs) => {
+            let lhs = evaluate(lhs, current, point)?;
+            let rhs = evaluate(rhs, current, point)?;
+            operator.apply(&lhs, &rhs)
+        }
+        Expr::Power(base, exponent) => evaluate(base, current, point)?.checked_pow(*exponent),
+    }
+}
+
+/// Appends the ops computing `expr` and returns where its value ends up.
+fn lower<T: Component>(expr: &Expr<T>, ops: &mut Vec<Op<T>>) -> Operand<T> {
+    let op = match expr {
+        Expr::Current => return Operand::Current,
+        Expr::Point => return Operand::Point,
+        Expr::Constant(constant) => return Operand::Constant(constant.clone()),
+        Expr::Binary(operator, lhs, rhs) => {
+            let lhs = lower(lhs, ops);
+            let rhs = lower(rhs, ops);
+            if *operator == Operator::Div
+                && let Operand::Constant(divisor) = &rhs
+                && let (Some(x), Some(y)) = (T::divisor(&divisor.x), T::divisor(&divisor.y))
+            {
+                Op::DivideBy(lhs, x, y)
+            } else {
+                Op::Binary(*operator, lhs, rhs)
+            }
+        }
+        Expr::Power(base, exponent) => Op::Power(lower(base, ops), *exponent),
+    };
+    ops.push(op);
+    Operand::Value(ops.len() - 1)
+}
+
+impl<T> Operand<T> {
+    fn get<'a>(
+        &'a self,
+        current: &'a ComplexNumber<T>,
+        point: &'a ComplexNumber<T>,
+        values: &'a [ComplexNumber<T>],
+    ) -> &'a ComplexNumber<T> {
+        match self {
+            Operand::Current => current,
+            Operand::Point => point,
+            Operand::Constant(constant) => constant,
+            Operand::Value(index) => &values[*index],
+        }
+    }
+}
+
+impl<T: Component> Compiled<T> {
+    /// [`Recurrence::next`]. `values` is scratch space for the results of [`Compiled::Ops`],
+    /// kept between calls so evaluating doesn't allocate.
+    pub fn next(
+        &self,
+        current: &ComplexNumber<T>,
+        point: &ComplexNumber<T>,
+        values: &mut Vec<ComplexNumber<T>>,
+    ) -> Option<ComplexNumber<T>> {
+        let (ops, result) = match self {
+            Compiled::Puzzle { x, y } => {
+                return current
+                    .checked_mul(current)?
+                    .checked_div_by(x, y)?
+                    .checked_add(point);
+            }
+            Compiled::Ops { ops, result } => (ops, result),
+        };
+        values.clear();
+        for op in ops {
+            let value = match op {
+                Op::Binary(operator, lhs, rhs) => operator.apply(
+                    lhs.get(current, point, values),
+                    rhs.get(current, point, values),
+                )?,
+                Op::DivideBy(lhs, x, y) => lhs.get(current, point, values).checked_div_by(x, y)?,
+                Op::Power(base, exponent) => {
+                    base.get(current, point, values).checked_pow(*exponent)?
+                }
+            };
+            values.push(value);
+        }
+        Some(result.get(current, point, values).clone())
+    }
+}
+
+impl<T: Component> FromStr for Recurrence<T> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Recurrence::parse(s)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Recurrence<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.expr)
+    }
+}
+
+/// Every operation is parenthesised, so the output always parses back to the same tree.
+impl<T: fmt::Display> fmt::Display for Expr<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expr::Current => write!(f, "R"),
+            Expr::Point => write!(f, "A"),
+            Expr::Constant(constant) => write!(f, "{}", constant),
+            Expr::Binary(operator, lhs, rhs) => {
+                write!(f, "({}{}{})", lhs, operator.symbol(), rhs)
+            }
+            Expr::Power(base, exponent) => write!(f, "({}^{})", base, exponent),
+        }
+    }
+}
+
+/// Recursive descent over `source`; `offset` is the byte position of the next token.
+struct Parser<'a> {
+    source: &'a str,
+    offset: usize,
+}
+
+impl Parser<'_> {
+    fn skip_whitespace(&mut self) {
+        let rest = &self.source[self.offset..];
+        self.offset += rest.len() - rest.trim_start().len();
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.source[self.offset..].chars().next()
+    }
+
+    /// The next character as a token slice, or the empty tail at the end of the input.
+    fn token(&self) -> &str {
+        let rest = &self.source[self.offset..];
+        &rest[..rest.chars().next().map_or(0, char::len_utf8)]
+    }
+
+    fn expr<T: Component>(&mut self) -> Result<Expr<T>, Error> {
+        let mut lhs = self.term()?;
+        while let Some(operator) = match self.peek() {
+            Some('+') => Some(Operator::Add),
+            Some('-') => Some(Operator::Sub),
+            _ => None,
+        } {
+            self.offset += 1;
+            lhs = Expr::Binary(operator, Box::new(lhs), Box::new(self.term()?));
+        }
+        Ok(lhs)
+    }
+
+    fn term<T: Component>(&mut self) -> Result<Expr<T>, Error> {
+        let mut lhs = self.power()?;
+        while let Some(operator) = match self.peek() {
+            Some('*') => Some(Operator::Mul),
+            Some('/') => Some(Operator::Div),
+            _ => None,
+        } {
+            self.offset += 1;
+            lhs = Expr::Binary(operator, Box::new(lhs), Box::new(self.power()?));
+        }
+        Ok(lhs)
+    }
+
+    fn power<T: Component>(&mut self) -> Result<Expr<T>, Error> {
+        let base = self.atom()?;
+        if self.peek() != Some('^') {
+            return Ok(base);
+        }
+        self.offset += 1;
+        self.skip_whitespace();
+        let rest = &self.source[self.offset..];
+        let digits = &rest[..rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len())];
+        if digits.is_empty() {
+            return Err(Error::format(
+                self.source,
+                self.token(),
+                "expected an exponent after '^'",
+            ));
+        }
+        let exponent = digits.parse::<u32>().map_err(|e| {
+            Error::invalid_value(self.source, digits, format!("invalid exponent: {}", e))
+        })?;
+        self.offset += digits.len();
+        Ok(Expr::Power(Box::new(base), exponent))
+    }
+
+    fn atom<T: Component>(&mut self) -> Result<Expr<T>, Error> {
+        match self.peek() {
+            Some('R') => {
+                self.offset += 1;
+                Ok(Expr::Current)
+            }
+            Some('A') => {
+                self.offset += 1;
+                Ok(Expr::Point)
+            }
+            Some('[') => {
+                let rest = &self.source[self.offset..];
+                let end = rest
+                    .find(']')
+                    .ok_or_else(|| Error::format(self.source, self.token(), "unclosed '['"))?;
+                let constant = parse_complex_number(self.source, &rest[..=end])?;
+                self.offset += end + 1;
+                Ok(Expr::Constant(constant))
+            }
+            Some('(') => {
+                let open = self.offset;
+                self.offset += 1;
+                let expr = self.expr()?;
+                if self.peek() != Some(')') {
+                    return Err(Error::format(
+                        self.source,
+                        &self.source[open..open + 1],
+                        "unclosed '('",
+                    ));
+                }
+                self.offset += 1;
+                Ok(expr)
+            }
+            _ => Err(Error::format(
+                self.source,
+                self.token(),
+                "expected R, A, [x,y] or '('",
+            )),
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use std::{
+    fs,
+    io::{self, Write},
+    path::Path,
+    str::FromStr,
+};
+
+use common::grid::Grid;
+use common::{Error, Part, part_params};
+
+use crate::{ComplexNumber, Quest2};
+
+/// Widest grid [`to_ascii`] is used for by [`trace`]; anything larger is unreadable in a terminal.
+pub const ASCII_PREVIEW_MAX_COLUMNS: usize = 200;
+
+/// Escaped points from early to late escape, engraved points are `X` like in the puzzle.
+const ASCII_SHADES: &[u8] = b" .:-=+*#%";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Ascii,
+    Pgm,
+    Png,
+}
+
+impl FromStr for ImageFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ascii" => Ok(ImageFormat::Ascii),
+            "pgm" => Ok(ImageFormat::Pgm),
+            "png" => Ok(ImageFormat::Png),
+            _ => Err(Error::param(
+                "trace",
+                format!("expected ascii, pgm or png, got \"{}\"", s),
+            )),
+        }
+    }
+}
+
+/// Grey level of a point: black for an immediate escape, brighter the longer it stayed
+/// bounded, white if it is engraved.
+pub fn shade(escape: Option<usize>, iterations: usize) -> u8 {
+    match escape {
+        Some(iteration) => (iteration * 255 / iterations.max(1)).min(254) as u8,
+        None => 255,
+    }
+}
+
+/// Binary (P5) PGM image with one pixel per point.
+pub fn to_pgm(grid: &Grid<Option<usize>>, iterations: usize) -> Vec<u8> {
+    let mut result = format!("P5\n{} {}\n255\n", grid.columns(), grid.rows()).into_bytes();
+    result.extend(grid.cells().iter().map(|&escape| shade(escape, iterations)));
+    result
+}
+
+#[cfg(feature = "png")]
+pub fn to_png(
+    grid: &Grid<Option<usize>>,
+    iterations: usize,
+) -> Result<Vec<u8>, png::EncodingError> {
+    let mut result = Vec::new();
+    let mut encoder = png::Encoder::new(&mut result, grid.columns() as u32, grid.rows() as u32);
+    encoder.set_color(png::ColorType::Grayscale);
+    encoder.set_depth(png::BitDepth::Eight);
+    let pixels = grid
+        .cells()
+        .iter()
+        .map(|&escape| shade(escape, iterations))
+        .collect::<Vec<_>>();
+    encoder.write_header()?.write_image_data(&pixels)?;
+    Ok(result)
+}
+
+pub fn to_ascii(grid: &Grid<Option<usize>>, iterations: usize) -> String {
+    let mut result = String::with_capacity(grid.len() + grid.rows());
+    for row in 0..grid.rows() {
+        for &escape in grid.row(row) {
+            result.push(match escape {
+                Some(iteration) => {
+                    let shade = iteration * ASCII_SHADES.len() / iterations.max(1);
+                    ASCII_SHADES[shade.min(ASCII_SHADES.len() - 1)] as char
+                }
+                None => 'X',
+            });
+        }
+        result.push('\n');
+    }
+    result
+}
+
+/// Solves part 2 or 3 while rendering the engraving grid to `out`, or to stdout for ASCII.
+pub fn trace(
+    file_path: &str,
+    part: Part,
+    params: &[(String, String)],
+    format: &str,
+    out: Option<&Path>,
+) -> Result<String, Error> {
+    let format = format.parse::<ImageFormat>()?;
+    if part == Part::One {
+        return Err(Error::param("trace", "only parts 2 and 3 can be traced"));
+    }
+    let part_params = part_params::<Quest2>(part, params)?;
+    let engraver = part_params.engraver();
+    let corner = ComplexNumber::<i64>::from_file(file_path)?;
+    let grid = engraver.escape_grid(&corner).ok_or_else(|| {
+        Error::unsolvable(format!("part {} overflowed i64 or divided by zero", part))
+    })?;
+
+    let rendered = match format {
+        ImageFormat::Ascii if grid.columns() > ASCII_PREVIEW_MAX_COLUMNS => {
+            return Err(Error::param(
+                "trace",
+                format!(
+                    "{} columns are too wide for an ascii preview, use pgm or png",
+                    grid.columns()
+                ),
+            ));
+        }
+        ImageFormat::Ascii => to_ascii(&grid, engraver.iterations).into_bytes(),
+        ImageFormat::Pgm => to_pgm(&grid, engraver.iterations),
+        #[cfg(feature = "png")]
+        ImageFormat::Png => to_png(&grid, engraver.iterations)
+            .map_err(|e| Error::unsolvable(format!("failed to encode png: {}", e)))?,
+        #[cfg(not(feature = "png"))]
+        ImageFormat::Png => {
+            return Err(Error::param(
+                "trace",
+                "png output needs the \"png\" feature of quest2",
+            ));
+        }
+    };
+    match (format, out) {
+        (_, Some(out)) => fs::write(out, rendered).map_err(|e| Error::io(out, e))?,
+        (ImageFormat::Ascii, None) => io::stdout()
+            .write_all(&rendered)
+            .map_err(|e| Error::io("stdout", e))?,
+        (_, None) => return Err(Error::param("trace-out", "images need an output file")),
+    }
+
+    let engraved = grid
+        .cells()
+        .iter()
+        .filter(|escape| escape.is_none())
+        .count();
+    Ok(engraved.to_string())
+}
@@ -0,0 +1,131 @@
+use common::{Error, Params, Part, Solution};
+use quest2::{BigInt, ComplexNumber, Component, Engraver, Quest2, Quest2Params, part_1, part_2};
+
+fn load<T: Component>(name: &str) -> ComplexNumber<T> {
+    ComplexNumber::from_file(&format!(
+        "{}/tests/input/{}",
+        env!("CARGO_MANIFEST_DIR"),
+        name
+    ))
+    .unwrap()
+}
+
+fn complex<T: Component>(x: T, y: T) -> ComplexNumber<T> {
+    ComplexNumber { x, y }
+}
+
+#[test]
+fn i32_overflow_is_none_instead_of_wrapping() {
+    let big = complex(i32::MAX, 1);
+    assert_eq!(big.checked_add(&complex(1, 0)), None);
+    assert_eq!(
+        complex(i32::MIN, 0).checked_sub(&complex(0, 0)),
+        Some(complex(i32::MIN, 0))
+    );
+    assert_eq!(complex(i32::MIN, 0).checked_sub(&complex(1, 0)), None);
+    assert_eq!(big.checked_mul(&complex(2, 0)), None);
+    // Each partial product fits, their difference doesn't.
+    assert_eq!(complex(46341, 0).checked_mul(&complex(46341, 0)), None);
+    assert_eq!(
+        complex(46340, 1).checked_mul(&complex(46340, -1)),
+        Some(complex(46340 * 46340 + 1, 0))
+    );
+    assert_eq!(
+        complex(i32::MIN, 4).checked_div_components(&complex(-1, 2)),
+        None
+    );
+    assert_eq!(complex(6, 4).checked_div_components(&complex(0, 2)), None);
+    assert_eq!(
+        complex(-7, 7).checked_div_components(&complex(2, -2)),
+        Some(complex(-3, -3))
+    );
+}
+
+#[test]
+fn overflowing_iterations_are_none_or_unsolvable() {
+    // Squaring the point gives y = 2 * 10^10, past i32::MAX.
+    let point = complex(100_000, 100_000);
+    assert_eq!(part_1::<i32>(point), None);
+    assert_eq!(
+        part_1::<i64>(complex(100_000, 100_000)).map(|result| result.to_string()),
+        Some("[-400039999999900000,40002000100000]".to_string())
+    );
+
+    let input = Quest2::parse_str("A=[3037000500,3037000500]").unwrap();
+    let params = Quest2Params::for_part(Part::One);
+    assert!(matches!(
+        Quest2::part1(&input, &params),
+        Err(Error::Unsolvable(_))
+    ));
+}
+
+#[test]
+fn engraving_overflow_is_reported_instead_of_counted_as_an_escape() {
+    // y² of the corner is past i32::MAX on the second iteration.
+    let engraver = Engraver::<i32> {
+        size: 10,
+        ..Engraver::new(1)
+    };
+    let corner = load::<i32>("test2.txt");
+    assert_eq!(engraver.count(&corner), None);
+    assert_eq!(engraver.escape_grid(&corner), None);
+    assert_eq!(engraver.is_engraved(&corner), None);
+    assert_eq!(
+        Engraver::<i64>::new(1).is_engraved(&load("test2.txt")),
+        Some(false)
+    );
+
+    let input = Quest2::parse_str("A=[35300,-64910]").unwrap();
+    for (key, value) in [
+        ("bound", "9000000000000000000"),
+        ("recurrence", "R/[0,1]+A"),
+    ] {
+        let mut params = Quest2Params::for_part(Part::Two);
+        params.set(key, value).unwrap();
+        assert!(
+            matches!(Quest2::part2(&input, &params), Err(Error::Unsolvable(_))),
+            "{}={}",
+            key,
+            value
+        );
+    }
+}
+
+#[test]
+fn wider_types_give_the_same_answers() {
+    let expected = part_1::<i64>(load("test1.txt")).unwrap().to_string();
+    assert_eq!(expected, "[357,862]");
+    assert_eq!(
+        part_1::<i32>(load("test1.txt")).unwrap().to_string(),
+        expected
+    );
+    assert_eq!(
+        part_1::<i128>(load("test1.txt")).unwrap().to_string(),
+        expected
+    );
+    assert_eq!(
+        part_1::<BigInt>(load("test1.txt")).unwrap().to_string(),
+        expected
+    );
+
+    let expected = part_2::<i64>(load("test2.txt"));
+    assert_eq!(expected, Some(4076));
+    assert_eq!(part_2::<i128>(load("test2.txt")), expected);
+    assert_eq!(part_2::<BigInt>(load("test2.txt")), expected);
+}
+
+#[test]
+fn wider_types_engrave_the_same_points() {
+    // A corner of the part 3 grid at full resolution.
+    fn count<T: Component + Send + Sync>() -> Option<usize> {
+        Engraver {
+            size: T::from(120),
+            ..Engraver::new(1)
+        }
+        .count(&load("test2.txt"))
+    }
+    let expected = count::<i64>();
+    assert!(expected.is_some_and(|count| count > 0));
+    assert_eq!(count::<i128>(), expected);
+    assert_eq!(count::<BigInt>(), expected);
+}
@@ -0,0 +1,71 @@
+use quest2::{ComplexNumber, Engraver, recurrence::Recurrence};
+
+fn sized(size: i64, step: i64) -> Engraver {
+    Engraver {
+        size,
+        step,
+        ..Engraver::new(1)
+    }
+}
+
+#[test]
+fn empty_grids_have_no_engraved_points() {
+    let corner = ComplexNumber::new(35300, -64910);
+    for (size, step) in [(-1, 1), (-1000, 10), (10, 0), (10, -5), (0, 0)] {
+        let engraver = sized(size, step);
+        assert_eq!(
+            engraver.count(&corner),
+            Some(0),
+            "size {} step {}",
+            size,
+            step
+        );
+        assert_eq!(engraver.count_with_threads(&corner, 0), Some(0));
+        let grid = engraver.escape_grid(&corner).unwrap();
+        assert!(grid.is_empty(), "size {} step {}", size, step);
+    }
+}
+
+#[test]
+fn thread_count_does_not_change_the_count() {
+    let corner = ComplexNumber::new(35300, -64910);
+    let engraver = sized(200, 3);
+    let expected = engraver.count_with_threads(&corner, 1);
+    assert!(expected.is_some_and(|count| count > 0));
+    for threads in [0, 2, 7, 100] {
+        assert_eq!(engraver.count_with_threads(&corner, threads), expected);
+    }
+
+    let single = sized(0, 1);
+    let grid = single.escape_grid(&corner).unwrap();
+    assert_eq!((grid.rows(), grid.columns()), (1, 1));
+    assert_eq!(
+        single.count(&corner),
+        Some(usize::from(grid[(0, 0)].is_none()))
+    );
+}
+
+#[test]
+fn overflowing_grids_are_none() {
+    let corner = ComplexNumber::new(i32::MAX - 5, 0);
+    let engraver = Engraver::<i32> {
+        size: 10,
+        ..Engraver::new(1)
+    };
+    assert_eq!(engraver.count(&corner), None);
+}
+
+#[test]
+fn puzzle_fast_path_engraves_like_the_general_evaluation() {
+    let corner = ComplexNumber::new(35300, -64910);
+    let puzzle = sized(150, 1);
+    // The same recurrence in a shape that isn't recognised as the puzzle's.
+    let general = Engraver {
+        recurrence: Recurrence::parse("A+R*R/[100000,100000]").unwrap(),
+        ..sized(150, 1)
+    };
+    assert_eq!(puzzle.escape_grid(&corner), general.escape_grid(&corner));
+    let count = puzzle.count(&corner);
+    assert!(count.is_some_and(|count| count > 0));
+    assert_eq!(general.count(&corner), count);
+}
@@ -0,0 +1,101 @@
+use quest2::ComplexNumber;
+
+fn complex(x: i64, y: i64) -> ComplexNumber {
+    ComplexNumber { x, y }
+}
+
+/// Every Gaussian integer with both components in `-range..=range`.
+fn square(range: i64) -> impl Iterator<Item = ComplexNumber> {
+    (-range..=range).flat_map(move |x| (-range..=range).map(move |y| complex(x, y)))
+}
+
+/// `number`, `i * number`, `-number` and `-i * number`.
+fn associates(number: ComplexNumber) -> [ComplexNumber; 4] {
+    let i = complex(0, 1);
+    [number, number * i, number * i * i, number * i * i * i]
+}
+
+#[test]
+fn conj_and_norm() {
+    assert_eq!(complex(3, -4).conj(), complex(3, 4));
+    assert_eq!(complex(-3, 0).conj(), complex(-3, 0));
+    assert_eq!(complex(3, -4).norm(), 25);
+    assert_eq!(complex(0, 0).norm(), 0);
+    for number in square(5) {
+        assert_eq!(number * number.conj(), complex(number.norm(), 0));
+    }
+}
+
+#[test]
+fn div_rem_leaves_a_small_remainder() {
+    for a in square(7) {
+        for b in square(4).filter(|b| b.norm() != 0) {
+            let (quotient, remainder) = a.div_rem(&b).unwrap();
+            assert_eq!(quotient * b + remainder, a, "{} / {}", a, b);
+            assert!(remainder.norm() < b.norm(), "{} / {}", a, b);
+            assert!(2 * remainder.norm() <= b.norm(), "{} / {}", a, b);
+        }
+    }
+
+    assert_eq!(
+        complex(-11, 7).div_rem(&complex(2, -3)),
+        Some((complex(-3, -1), complex(-2, 0)))
+    );
+    assert_eq!(complex(-11, 7) / complex(2, -3), complex(-3, -1));
+    assert_eq!(complex(-11, 7) % complex(2, -3), complex(-2, 0));
+}
+
+#[test]
+fn div_rem_by_zero_is_none() {
+    assert_eq!(complex(5, 3).div_rem(&complex(0, 0)), None);
+    assert_eq!(complex(0, 0).div_rem(&complex(0, 0)), None);
+}
+
+#[test]
+#[should_panic(expected = "Gaussian division by zero")]
+fn dividing_by_zero_with_the_operator_panics() {
+    let _ = complex(5, 3) / complex(0, 0);
+}
+
+#[test]
+fn gcd_of_associates_is_the_canonical_associate() {
+    for number in square(6).filter(|number| number.norm() != 0) {
+        let gcds = associates(number)
+            .into_iter()
+            .flat_map(|a| associates(number).map(|b| a.gcd(&b)))
+            .collect::<Vec<ComplexNumber>>();
+        let canonical = gcds[0];
+        assert!(canonical.x > 0 && canonical.y >= 0, "{}", number);
+        assert!(associates(number).contains(&canonical), "{}", number);
+        assert!(gcds.iter().all(|gcd| *gcd == canonical), "{}", number);
+    }
+}
+
+#[test]
+fn gcd_divides_both_operands() {
+    // 5 = (2 + i)(2 - i) and 3 + i = (1 + i)(2 - i), whose canonical associate is 1 + 2i.
+    assert_eq!(complex(5, 0).gcd(&complex(3, 1)), complex(1, 2));
+    assert_eq!(complex(3, 1).gcd(&complex(5, 0)), complex(1, 2));
+    assert_eq!(complex(-7, 0).gcd(&complex(0, 3)), complex(1, 0));
+
+    for a in square(4) {
+        for b in square(4) {
+            let gcd = a.gcd(&b);
+            if gcd.norm() == 0 {
+                assert_eq!((a, b), (complex(0, 0), complex(0, 0)));
+                continue;
+            }
+            assert_eq!(a % gcd, complex(0, 0), "gcd({}, {})", a, b);
+            assert_eq!(b % gcd, complex(0, 0), "gcd({}, {})", a, b);
+        }
+    }
+}
+
+#[test]
+fn gcd_with_zero_is_the_other_operand() {
+    let zero = complex(0, 0);
+    assert_eq!(zero.gcd(&zero), zero);
+    assert_eq!(complex(0, -3).gcd(&zero), complex(3, 0));
+    assert_eq!(zero.gcd(&complex(-2, -1)), complex(2, 1));
+    assert_eq!(zero.gcd(&complex(4, 2)), complex(4, 2));
+}
@@ -0,0 +1,43 @@
+use common::{Error, Params, Part};
+use quest2::Quest2Params;
+
+fn set(key: &str, value: &str) -> Result<Quest2Params, Error> {
+    let mut params = Quest2Params::for_part(Part::Two);
+    params.set(key, value)?;
+    Ok(params)
+}
+
+#[test]
+fn engraving_params_are_validated() {
+    for (key, value, message) in [
+        ("step", "0", "expected at least 1, got 0"),
+        ("step", "-5", "expected at least 1, got -5"),
+        ("size", "-1", "expected at least 0, got -1"),
+        ("bound", "-1", "expected at least 0, got -1"),
+        ("step", "ten", "\"ten\""),
+        ("iterations", "-1", "\"-1\""),
+        ("depth", "3", "unknown param"),
+    ] {
+        let error = set(key, value).err().unwrap();
+        assert!(
+            matches!(&error, Error::Param { key: error_key, .. } if error_key == key),
+            "{}={}: {:?}",
+            key,
+            value,
+            error
+        );
+        assert!(error.to_string().contains(message), "{}", error);
+    }
+}
+
+#[test]
+fn valid_engraving_params_are_stored() {
+    let params = set("step", "1").unwrap();
+    assert_eq!(params.step, 1);
+    assert_eq!(set("size", "0").unwrap().size, 0);
+    assert_eq!(set("bound", "0").unwrap().bound, 0);
+    assert_eq!(set("iterations", "0").unwrap().iterations, 0);
+
+    let engraver = set("size", "20").unwrap().engraver();
+    assert_eq!((engraver.size, engraver.step), (20, 10));
+}
@@ -0,0 +1,81 @@
+use quest2::reciprocal::Reciprocal;
+
+/// Deterministic values spread over the whole range, plus their neighbours of zero.
+fn samples(count: usize) -> impl Iterator<Item = u64> {
+    let mut state = 0x2545f4914f6cdd1d_u64;
+    (0..count).map(move |i| {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        // Every third sample small, so small divisors and quotients are covered too.
+        if i % 3 == 0 { state % 1000 } else { state }
+    })
+}
+
+#[test]
+fn i64_reciprocals_divide_like_the_division_operator() {
+    let edges = [
+        0,
+        1,
+        -1,
+        2,
+        -2,
+        3,
+        7,
+        10,
+        100000,
+        -100000,
+        1 << 32,
+        i64::MAX,
+        i64::MAX - 1,
+        i64::MIN,
+        i64::MIN + 1,
+    ];
+    let divisors = edges
+        .into_iter()
+        .chain(samples(200).map(|sample| sample as i64));
+    for divisor in divisors {
+        let Some(reciprocal) = Reciprocal::<i64, u64>::new(divisor) else {
+            assert_eq!(divisor, 0);
+            continue;
+        };
+        let numerators = edges
+            .into_iter()
+            .chain(samples(500).map(|sample| sample as i64));
+        for n in numerators {
+            assert_eq!(
+                reciprocal.checked_div(n),
+                n.checked_div(divisor),
+                "{} / {}",
+                n,
+                divisor
+            );
+        }
+    }
+}
+
+#[test]
+fn i32_reciprocals_divide_like_the_division_operator() {
+    let edges = [0, 1, -1, 2, 3, 10, 46341, i32::MAX, i32::MIN, i32::MIN + 1];
+    let divisors = edges
+        .into_iter()
+        .chain(samples(200).map(|sample| sample as i32));
+    for divisor in divisors {
+        let Some(reciprocal) = Reciprocal::<i32, u32>::new(divisor) else {
+            assert_eq!(divisor, 0);
+            continue;
+        };
+        let numerators = edges
+            .into_iter()
+            .chain(samples(500).map(|sample| sample as i32));
+        for n in numerators {
+            assert_eq!(
+                reciprocal.checked_div(n),
+                n.checked_div(divisor),
+                "{} / {}",
+                n,
+                divisor
+            );
+        }
+    }
+}
@@ -0,0 +1,242 @@
+use quest2::{
+    ComplexNumber,
+    recurrence::{Compiled, Expr, Operand, Operator, Recurrence},
+};
+
+fn parse(source: &str) -> Recurrence {
+    Recurrence::parse(source).unwrap_or_else(|e| panic!("{}: {}", source, e.diagnostic()))
+}
+
+fn complex(x: i64, y: i64) -> ComplexNumber {
+    ComplexNumber { x, y }
+}
+
+#[test]
+fn puzzle_matches_its_written_form() {
+    assert_eq!(Recurrence::<i64>::puzzle(10), parse("R*R/[10,10]+A"));
+    assert_eq!(
+        Recurrence::<i64>::puzzle(100000),
+        parse("R * R / [100000, 100000] + A")
+    );
+    assert_eq!(
+        Recurrence::<i64>::puzzle(10).to_string(),
+        "(((R*R)/[10,10])+A)"
+    );
+}
+
+#[test]
+fn multiplication_binds_tighter_than_addition() {
+    assert_eq!(parse("A+R*R").to_string(), "(A+(R*R))");
+    assert_eq!(parse("A*R-R/A").to_string(), "((A*R)-(R/A))");
+    assert_eq!(parse("(A+R)*R").to_string(), "((A+R)*R)");
+    assert_eq!(parse("R*R^2").to_string(), "(R*(R^2))");
+}
+
+#[test]
+fn binary_operators_associate_to_the_left() {
+    assert_eq!(parse("A-R-R").to_string(), "((A-R)-R)");
+    assert_eq!(parse("R/A/A").to_string(), "((R/A)/A)");
+    assert_eq!(parse("A-(R-R)").to_string(), "(A-(R-R))");
+}
+
+#[test]
+fn powers_take_a_literal_exponent_and_do_not_chain() {
+    assert_eq!(
+        parse("(R^2)^3").expr,
+        Expr::Power(Box::new(Expr::Power(Box::new(Expr::Current), 2)), 3)
+    );
+    let nested = parse("(R^2)^3");
+    let flat = parse("R^6");
+    let point = complex(0, 0);
+    for current in [complex(1, 1), complex(-2, 3), complex(0, 5)] {
+        assert_eq!(nested.next(&current, &point), flat.next(&current, &point));
+    }
+
+    let error = Recurrence::<i64>::parse("R^2^3").unwrap_err();
+    assert_eq!(error.location().unwrap().column, 4);
+    assert!(
+        error.to_string().contains("expected an operator"),
+        "{}",
+        error
+    );
+}
+
+#[test]
+fn literal_constants() {
+    assert_eq!(parse("[3,-4]").expr, Expr::Constant(complex(3, -4)));
+    assert_eq!(parse(" [ 3 , -4 ] ").expr, Expr::Constant(complex(3, -4)));
+    assert_eq!(
+        parse("A+[0,1]").expr,
+        Expr::Binary(
+            Operator::Add,
+            Box::new(Expr::Point),
+            Box::new(Expr::Constant(complex(0, 1)))
+        )
+    );
+}
+
+#[test]
+fn evaluates_against_the_current_value_and_point() {
+    // (1 + 2i)² + 3 = -3 + 4i + 3.
+    assert_eq!(
+        parse("R^2+A").next(&complex(1, 2), &complex(3, 0)),
+        Some(complex(0, 4))
+    );
+    assert_eq!(
+        parse("R^0").next(&complex(7, 7), &complex(0, 0)),
+        Some(complex(1, 0))
+    );
+    assert_eq!(
+        parse("R/[2,3]").next(&complex(-7, 9), &complex(0, 0)),
+        Some(complex(-3, 3))
+    );
+    assert_eq!(parse("R/[2,0]").next(&complex(4, 4), &complex(0, 0)), None);
+    assert_eq!(
+        parse("R*R").next(&complex(i64::MAX, 0), &complex(0, 0)),
+        None
+    );
+    assert_eq!(
+        Recurrence::<i64>::puzzle(10).iterate(&complex(25, 9), 3),
+        Some(complex(357, 862))
+    );
+}
+
+#[test]
+fn huge_exponents_are_evaluated_by_squaring() {
+    let huge = parse("R^4000000000+A");
+    let point = complex(3, -2);
+    for (current, expected) in [
+        (complex(0, 0), Some(complex(3, -2))),
+        (complex(1, 0), Some(complex(4, -2))),
+        (complex(-1, 0), Some(complex(4, -2))),
+        (complex(0, 1), Some(complex(4, -2))),
+        (complex(2, 0), None),
+    ] {
+        assert_eq!(huge.next(&current, &point), expected, "{}", current);
+    }
+
+    for base in [complex(2, 1), complex(-3, 0), complex(1, -1)] {
+        let mut expected = complex(1, 0);
+        for exponent in 0..20 {
+            assert_eq!(
+                base.checked_pow(exponent),
+                Some(expected),
+                "{}^{}",
+                base,
+                exponent
+            );
+            expected = expected.checked_mul(&base).unwrap();
+        }
+    }
+    // The last power fits even though squaring once more wouldn't.
+    assert_eq!(complex(2, 0).checked_pow(62), Some(complex(1 << 62, 0)));
+    assert_eq!(complex(2, 0).checked_pow(63), None);
+}
+
+#[test]
+fn compiling_keeps_the_puzzle_on_its_fast_path() {
+    assert!(matches!(
+        Recurrence::<i64>::puzzle(10).compile(),
+        Compiled::Puzzle { .. }
+    ));
+    assert!(matches!(
+        parse("R*R/[3,-7]+A").compile(),
+        Compiled::Puzzle { .. }
+    ));
+    for source in [
+        "A+R*R/[10,10]",
+        "R*R/[0,10]+A",
+        "R*A/[10,10]+A",
+        "R^2/[10,10]+A",
+    ] {
+        assert!(
+            matches!(parse(source).compile(), Compiled::Ops { .. }),
+            "{}",
+            source
+        );
+    }
+    let Compiled::Ops { ops, result } = parse("A").compile() else {
+        panic!("a lone operand isn't the puzzle");
+    };
+    assert!(ops.is_empty());
+    assert_eq!(result, Operand::Point);
+}
+
+#[test]
+fn compiled_recurrences_evaluate_like_the_tree() {
+    let mut values = Vec::new();
+    for source in [
+        "R*R/[10,10]+A",
+        "R*R/[-3,7]+A",
+        "A+R*R/[10,10]",
+        "R*R*R+[-1,2]*A/[3,3]",
+        "(R^2)^3-A",
+        "R/A",
+        "R/[0,1]",
+        "[5,-5]",
+        "R",
+        "A-(R-R)",
+    ] {
+        let recurrence = parse(source);
+        let compiled = recurrence.compile();
+        for (current, point) in [
+            (complex(0, 0), complex(1, 1)),
+            (complex(25, 9), complex(-4, 3)),
+            (complex(-1000, 731), complex(0, 17)),
+            (complex(i64::MAX / 2, 3), complex(1, 1)),
+            (complex(i64::MIN, -1), complex(-1, 0)),
+        ] {
+            assert_eq!(
+                compiled.next(&current, &point, &mut values),
+                recurrence.next(&current, &point),
+                "{} at R={} A={}",
+                source,
+                current,
+                point
+            );
+        }
+    }
+}
+
+#[test]
+fn malformed_expressions_point_at_the_problem() {
+    for (source, column, token, message) in [
+        ("R*", 3, "", "expected R, A, [x,y] or '('"),
+        ("R+Q", 3, "Q", "expected R, A, [x,y] or '('"),
+        ("R R", 3, "R", "expected an operator or the end"),
+        ("R+(A*R", 3, "(", "unclosed '('"),
+        ("R+[1,2", 3, "[", "unclosed '['"),
+        ("R^x", 3, "x", "expected an exponent after '^'"),
+        ("R^99999999999", 3, "99999999999", "invalid exponent"),
+        ("A+[1,z]", 6, "z", "invalid y value"),
+        ("", 1, "", "expected R, A, [x,y] or '('"),
+    ] {
+        let error = Recurrence::<i64>::parse(source).unwrap_err();
+        let location = error.location().expect("located error");
+        assert_eq!(
+            (location.line, location.column, location.token.as_str()),
+            (1, column, token),
+            "{}",
+            source
+        );
+        assert!(error.to_string().contains(message), "{}: {}", source, error);
+    }
+}
+
+#[test]
+fn display_parses_back_to_the_same_recurrence() {
+    for source in [
+        "R*R/[10,10]+A",
+        "A-R-R",
+        "A-(R-R)",
+        "(R^2)^3",
+        "R*R*R+[-1,2]*A/[3,3]",
+        "((A))",
+        "R^0-[0,-7]",
+    ] {
+        let recurrence = parse(source);
+        let displayed = recurrence.to_string();
+        assert_eq!(parse(&displayed), recurrence, "{} -> {}", source, displayed);
+        assert_eq!(displayed.parse::<Recurrence>().unwrap(), recurrence);
+    }
+}
@@ -0,0 +1,107 @@
+use std::{fs, path::PathBuf};
+
+use common::{Error, Part, grid::Grid};
+use quest2::render::{ASCII_PREVIEW_MAX_COLUMNS, shade, to_ascii, to_pgm, trace};
+
+const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/input/test2.txt");
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("quest2-render-{}-{}", name, std::process::id()))
+}
+
+fn params(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+/// Two rows of three points: escapes at 0, 50 and 98 of 99 iterations, then engraved ones.
+fn grid() -> Grid<Option<usize>> {
+    let mut grid = Grid::filled(2, 3, None);
+    grid[(0, 0)] = Some(0);
+    grid[(0, 1)] = Some(50);
+    grid[(0, 2)] = Some(98);
+    grid
+}
+
+#[test]
+fn shade_keeps_white_for_engraved_points() {
+    assert_eq!(shade(Some(0), 99), 0);
+    assert_eq!(shade(Some(98), 99), 252);
+    assert_eq!(shade(Some(99), 99), 254);
+    assert_eq!(shade(Some(1000), 99), 254);
+    assert_eq!(shade(None, 99), 255);
+    assert_eq!(shade(Some(0), 0), 0);
+    assert_eq!(shade(None, 0), 255);
+}
+
+#[test]
+fn pgm_has_a_p5_header_and_one_byte_per_point() {
+    let pgm = to_pgm(&grid(), 99);
+    let header = b"P5\n3 2\n255\n";
+    assert!(pgm.starts_with(header));
+    assert_eq!(pgm.len(), header.len() + 6);
+    assert_eq!(&pgm[header.len()..], [0, 128, 252, 255, 255, 255]);
+}
+
+#[test]
+fn ascii_shades_escapes_and_marks_engraved_points() {
+    assert_eq!(to_ascii(&grid(), 99), " =%\nXXX\n");
+
+    // The last iterations before the limit get the darkest shade, never past it.
+    let mut late = Grid::filled(1, 3, None);
+    late[(0, 0)] = Some(8);
+    late[(0, 1)] = Some(9);
+    late[(0, 2)] = Some(10);
+    assert_eq!(to_ascii(&late, 10), "#%%\n");
+}
+
+#[test]
+fn trace_writes_ascii_and_pgm_files() {
+    let small = params(&[("size", "20"), ("step", "10")]);
+
+    let ascii = temp_path("ascii.txt");
+    let engraved = trace(INPUT, Part::Two, &small, "ascii", Some(&ascii)).unwrap();
+    let rendered = fs::read_to_string(&ascii).unwrap();
+    assert_eq!(rendered.lines().count(), 3);
+    assert!(rendered.lines().all(|line| line.len() == 3), "{}", rendered);
+    assert_eq!(rendered.matches('X').count().to_string(), engraved);
+
+    let pgm = temp_path("image.pgm");
+    trace(INPUT, Part::Two, &small, "pgm", Some(&pgm)).unwrap();
+    let image = fs::read(&pgm).unwrap();
+    assert!(image.starts_with(b"P5\n3 3\n255\n"));
+    assert_eq!(image.len(), b"P5\n3 3\n255\n".len() + 9);
+
+    fs::remove_file(ascii).unwrap();
+    fs::remove_file(pgm).unwrap();
+}
+
+#[test]
+fn trace_rejects_wide_ascii_previews() {
+    let columns = ASCII_PREVIEW_MAX_COLUMNS + 1;
+    let wide = params(&[("size", &(columns - 1).to_string()), ("step", "1")]);
+    let error = trace(INPUT, Part::Three, &wide, "ascii", None).unwrap_err();
+    assert!(matches!(error, Error::Param { ref key, .. } if key == "trace"));
+    assert!(
+        error
+            .to_string()
+            .contains(&format!("{} columns are too wide", columns)),
+        "{}",
+        error
+    );
+
+    let error = trace(INPUT, Part::One, &[], "ascii", None).unwrap_err();
+    assert!(
+        error.to_string().contains("only parts 2 and 3"),
+        "{}",
+        error
+    );
+    let error = trace(INPUT, Part::Two, &[], "pgm", None).unwrap_err();
+    assert!(
+        error.to_string().contains("need an output file"),
+        "{}",
+        error
+    );
+}
@@ -0,0 +1,104 @@
+pub mod packing;
+
+use common::input::fields;
+use common::{Error, Input, Params, Part, Solution, parse_param};
+
+pub use packing::{Packer, Packing, Selection, weight};
+
+pub fn extract_input_from_file(file_path: &str) -> Result<Vec<u32>, Error> {
+    parse_input(&Input::open(file_path)?)
+}
+
+pub fn parse_input(input: &Input) -> Result<Vec<u32>, Error> {
+    fields(input.trimmed(), ',')
+        .map(|s| {
+            s.parse::<u32>()
+                .map_err(|e| input.invalid_value(s, format!("couldn't parse to u32: {}", e)))
+        })
+        .collect()
+}
+
+pub fn part1(input: &[u32]) -> u64 {
+    weight(&Quest3Params::for_part(Part::One).packer().best_set(input))
+}
+
+pub fn part2(input: &[u32]) -> u64 {
+    weight(&Quest3Params::for_part(Part::Two).packer().best_set(input))
+}
+
+pub fn part3(input: &[u32]) -> usize {
+    Quest3Params::for_part(Part::Three)
+        .packer()
+        .pack_all(input)
+        .sets
+        .len()
+}
+
+pub struct Quest3Params {
+    /// The most crates a set may hold, `None` for no limit.
+    pub set_size: Option<usize>,
+    pub select: Selection,
+}
+
+impl Quest3Params {
+    pub fn packer(&self) -> Packer {
+        Packer {
+            max_set_size: self.set_size,
+            selection: self.select,
+        }
+    }
+}
+
+impl Params for Quest3Params {
+    fn for_part(part: Part) -> Self {
+        match part {
+            Part::Two => Quest3Params {
+                set_size: Some(20),
+                select: Selection::Smallest,
+            },
+            Part::One | Part::Three => Quest3Params {
+                set_size: None,
+                select: Selection::Largest,
+            },
+        }
+    }
+
+    fn set(&mut self, key: &str, value: &str) -> Result<(), Error> {
+        match key {
+            "set_size" if value == "all" => self.set_size = None,
+            "set_size" => match parse_param::<usize>(key, value)? {
+                0 => return Err(Error::param(key, "a set holds at least one crate")),
+                size => self.set_size = Some(size),
+            },
+            "select" => self.select = value.parse()?,
+            _ => return Err(Error::param(key, "unknown param")),
+        }
+        Ok(())
+    }
+}
+
+pub struct Quest3;
+
+impl Solution for Quest3 {
+    type Input = Vec<u32>;
+    type Params = Quest3Params;
+    type Answer1 = u64;
+    type Answer2 = u64;
+    type Answer3 = usize;
+
+    fn parse_input(input: &Input) -> Result<Self::Input, Error> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Vec<u32>, params: &Quest3Params) -> Result<u64, Error> {
+        Ok(weight(&params.packer().best_set(input)))
+    }
+
+    fn part2(input: &Vec<u32>, params: &Quest3Params) -> Result<u64, Error> {
+        Ok(weight(&params.packer().best_set(input)))
+    }
+
+    fn part3(input: &Vec<u32>, params: &Quest3Params) -> Result<usize, Error> {
+        Ok(params.packer().pack_all(input).sets.len())
+    }
+}
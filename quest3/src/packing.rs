@@ -0,0 +1,138 @@
+use std::{fmt, str::FromStr};
+
+use common::Error;
+use itertools::Itertools;
+
+/// Which end of the sizes a set is filled from when it can't hold all of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Selection {
+    Largest,
+    Smallest,
+}
+
+impl FromStr for Selection {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "largest" => Ok(Selection::Largest),
+            "smallest" => Ok(Selection::Smallest),
+            _ => Err(Error::param(
+                "select",
+                format!("expected largest or smallest, got \"{}\"", s),
+            )),
+        }
+    }
+}
+
+/// Crates nested into sets. Each set holds distinct sizes, largest first, since a crate only
+/// fits into a strictly larger one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Packing {
+    pub sets: Vec<Vec<u32>>,
+}
+
+impl Packing {
+    pub fn weights(&self) -> Vec<u64> {
+        self.sets.iter().map(|set| weight(set)).collect()
+    }
+
+    /// Checks that every set is strictly decreasing and within `max_set_size`, and, with
+    /// `crates`, that the sets hold exactly those crates.
+    pub fn validate(
+        &self,
+        max_set_size: Option<usize>,
+        crates: Option<&[u32]>,
+    ) -> Result<(), Error> {
+        for (index, set) in self.sets.iter().enumerate() {
+            if let Some((larger, smaller)) = set.iter().tuple_windows().find(|(a, b)| a <= b) {
+                return Err(Error::unsolvable(format!(
+                    "set {} puts {} into {}",
+                    index + 1,
+                    smaller,
+                    larger
+                )));
+            }
+            if let Some(max) = max_set_size.filter(|&max| set.len() > max) {
+                return Err(Error::unsolvable(format!(
+                    "set {} holds {} crates, more than {}",
+                    index + 1,
+                    set.len(),
+                    max
+                )));
+            }
+        }
+        if let Some(crates) = crates {
+            let packed = self.sets.iter().flatten().sorted().collect::<Vec<_>>();
+            if packed != crates.iter().sorted().collect::<Vec<_>>() {
+                return Err(Error::unsolvable(
+                    "the sets don't hold exactly the given crates",
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Packing {
+    /// One set per line, e.g. `10 > 8 > 3 (21)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for set in &self.sets {
+            writeln!(f, "{} ({})", set.iter().join(" > "), weight(set))?;
+        }
+        Ok(())
+    }
+}
+
+pub fn weight(set: &[u32]) -> u64 {
+    set.iter().map(|&size| u64::from(size)).sum()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Packer {
+    /// `None` for no limit, otherwise at least 1.
+    pub max_set_size: Option<usize>,
+    pub selection: Selection,
+}
+
+impl Packer {
+    /// The heaviest (or, selecting the smallest, lightest) single set: distinct sizes taken
+    /// from the selected end, up to the size limit.
+    pub fn best_set(&self, crates: &[u32]) -> Vec<u32> {
+        let sizes = crates.iter().copied().unique().sorted();
+        let set = match self.selection {
+            Selection::Largest => sizes.rev().take(self.limit()).collect::<Vec<u32>>(),
+            Selection::Smallest => sizes.take(self.limit()).collect(),
+        };
+        set.into_iter().sorted_by(|a, b| b.cmp(a)).collect()
+    }
+
+    /// Every crate in as few sets as possible. A size appearing `k` times needs `k` sets and
+    /// `n` crates need `n / max_set_size` (rounded up), whichever is more. Dealing the crates
+    /// out round-robin in size order, from the selected end, meets that bound with sets whose
+    /// sizes differ by at most one; filling one set at a time wouldn't under a size limit.
+    pub fn pack_all(&self, crates: &[u32]) -> Packing {
+        if crates.is_empty() {
+            return Packing { sets: Vec::new() };
+        }
+        let max_multiplicity = crates.iter().counts().into_values().max().unwrap_or(0);
+        let count = max_multiplicity.max(crates.len().div_ceil(self.limit()));
+
+        let ordered = match self.selection {
+            Selection::Largest => crates.iter().copied().sorted_by(|a, b| b.cmp(a)).collect(),
+            Selection::Smallest => crates.iter().copied().sorted().collect::<Vec<u32>>(),
+        };
+        let mut sets = vec![Vec::new(); count];
+        for (index, size) in ordered.into_iter().enumerate() {
+            sets[index % count].push(size);
+        }
+        for set in &mut sets {
+            set.sort_unstable_by(|a, b| b.cmp(a));
+        }
+        Packing { sets }
+    }
+
+    fn limit(&self) -> usize {
+        self.max_set_size.unwrap_or(usize::MAX)
+    }
+}
@@ -0,0 +1,74 @@
+use quest3::{Packer, Selection, weight};
+
+const CRATES: [u32; 9] = [10, 5, 1, 10, 3, 8, 5, 2, 2];
+
+#[test]
+fn best_set_takes_distinct_sizes_from_the_selected_end() {
+    let largest = Packer {
+        max_set_size: None,
+        selection: Selection::Largest,
+    };
+    assert_eq!(largest.best_set(&CRATES), [10, 8, 5, 3, 2, 1]);
+    assert_eq!(weight(&largest.best_set(&CRATES)), 29);
+
+    let three = Packer {
+        max_set_size: Some(3),
+        ..largest
+    };
+    assert_eq!(three.best_set(&CRATES), [10, 8, 5]);
+    let smallest = Packer {
+        selection: Selection::Smallest,
+        ..three
+    };
+    assert_eq!(smallest.best_set(&CRATES), [3, 2, 1]);
+}
+
+#[test]
+fn pack_all_uses_as_few_sets_as_possible() {
+    for (max_set_size, sets) in [
+        (None, 2),
+        (Some(6), 2),
+        (Some(4), 3),
+        (Some(2), 5),
+        (Some(1), 9),
+    ] {
+        for selection in [Selection::Largest, Selection::Smallest] {
+            let packer = Packer {
+                max_set_size,
+                selection,
+            };
+            let packing = packer.pack_all(&CRATES);
+            assert_eq!(packing.sets.len(), sets, "{:?}", packer);
+            packing
+                .validate(max_set_size, Some(&CRATES))
+                .unwrap_or_else(|e| panic!("{:?}: {}\n{}", packer, e, packing));
+        }
+    }
+
+    let packing = Packer {
+        max_set_size: None,
+        selection: Selection::Largest,
+    }
+    .pack_all(&CRATES);
+    assert_eq!(packing.sets, [vec![10, 8, 5, 2, 1], vec![10, 5, 3, 2]]);
+    assert_eq!(packing.weights(), [26, 20]);
+    assert_eq!(
+        packing.to_string(),
+        "10 > 8 > 5 > 2 > 1 (26)\n10 > 5 > 3 > 2 (20)\n"
+    );
+}
+
+#[test]
+fn validate_rejects_bad_packings() {
+    let packing = Packer {
+        max_set_size: None,
+        selection: Selection::Largest,
+    }
+    .pack_all(&[4, 4, 2]);
+    assert!(packing.validate(Some(1), None).is_err());
+    assert!(packing.validate(None, Some(&[4, 2])).is_err());
+
+    let mut broken = packing.clone();
+    broken.sets[0].push(9);
+    assert!(broken.validate(None, None).is_err());
+}
@@ -0,0 +1,100 @@
+use std::ops::Mul;
+
+use common::{Error, Input, Solution};
+use itertools::Itertools;
+
+pub fn extract_input_from_file(file_path: &str) -> Result<Vec<u32>, Error> {
+    parse_input(&Input::open(file_path)?)
+}
+
+pub fn parse_input(input: &Input) -> Result<Vec<u32>, Error> {
+    input
+        .lines()
+        .map(|line| {
+            line.parse::<u32>()
+                .map_err(|e| input.invalid_value(line, format!("failed to parse line: {}", e)))
+        })
+        .collect()
+}
+
+/// Reads one gear per line, either `teeth` or a `left|right` pair of gears sharing an axle.
+/// A single gear is returned as `(teeth, teeth)`, so part 1 and 2 inputs parse as well.
+pub fn extract_input_from_file_part3(file_path: &str) -> Result<Vec<(u32, u32)>, Error> {
+    parse_input_part3(&Input::open(file_path)?)
+}
+
+pub fn parse_input_part3(input: &Input) -> Result<Vec<(u32, u32)>, Error> {
+    input
+        .lines()
+        .map(|line| {
+            let parts: (&str, &str) = line.split_once('|').unwrap_or((line, line));
+            let num1: u32 = parts.0.parse::<u32>().map_err(|e| {
+                input.invalid_value(parts.0, format!("failed to parse number 1: {}", e))
+            })?;
+            let num2: u32 = parts.1.parse::<u32>().map_err(|e| {
+                input.invalid_value(parts.1, format!("failed to parse number 2: {}", e))
+            })?;
+            Ok((num1, num2))
+        })
+        .collect()
+}
+
+pub fn part1(input: &[u32]) -> u32 {
+    input
+        .iter()
+        .map(|x| *x as f64)
+        .tuple_windows()
+        .fold(1.0, |acc, (a, b)| acc * (a / b))
+        .mul(2025.0)
+        .floor() as u32
+}
+
+pub fn part2(input: &[u32]) -> u64 {
+    (10000000000000.0
+        / input
+            .iter()
+            .map(|x| *x as f64)
+            .tuple_windows()
+            .fold(1.0, |acc, (a, b)| acc * (a / b)))
+    .ceil() as u64
+}
+
+pub fn part3(input: &[(u32, u32)]) -> u64 {
+    input
+        .iter()
+        .map(|(a, b)| (*a as f64, *b as f64))
+        .tuple_windows()
+        .fold(1.0, |acc, ((_, b1), (a2, _))| acc * (b1 / a2))
+        .mul(100.0)
+        .floor() as u64
+}
+
+pub struct Quest4;
+
+impl Solution for Quest4 {
+    type Input = Vec<(u32, u32)>;
+    type Params = ();
+    type Answer1 = u32;
+    type Answer2 = u64;
+    type Answer3 = u64;
+
+    fn parse_input(input: &Input) -> Result<Self::Input, Error> {
+        parse_input_part3(input)
+    }
+
+    fn part1(input: &Vec<(u32, u32)>, _: &()) -> Result<u32, Error> {
+        Ok(part1(
+            &input.iter().map(|(teeth, _)| *teeth).collect::<Vec<u32>>(),
+        ))
+    }
+
+    fn part2(input: &Vec<(u32, u32)>, _: &()) -> Result<u64, Error> {
+        Ok(part2(
+            &input.iter().map(|(teeth, _)| *teeth).collect::<Vec<u32>>(),
+        ))
+    }
+
+    fn part3(input: &Vec<(u32, u32)>, _: &()) -> Result<u64, Error> {
+        Ok(part3(input))
+    }
+}
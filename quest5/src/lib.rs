@@ -0,0 +1,254 @@
+use common::input::fields;
+use common::{Error, Input, Solution};
+
+#[derive(Debug)]
+pub struct Fishbone {
+    pub id: u32,
+    pub nodes: Vec<FishboneNode>,
+}
+
+pub struct SimplifiedFishbone {
+    pub id: u32,
+    pub nodes: Vec<u32>,
+}
+
+#[derive(Debug)]
+pub struct FishboneNode {
+    pub value: u32,
+    pub lhs: Option<u32>,
+    pub rhs: Option<u32>,
+}
+
+pub fn part1(input: &[SimplifiedFishbone]) -> String {
+    let mut fishbone = Fishbone {
+        id: input[0].id,
+        nodes: vec![FishboneNode {
+            value: input[0].nodes[0],
+            lhs: None,
+            rhs: None,
+        }],
+    };
+    for num in input[0].nodes.iter().skip(1) {
+        let mut num_placed = false;
+        for node in &mut fishbone.nodes {
+            if node.lhs.is_none() && *num < node.value {
+                node.lhs = Some(*num);
+                num_placed = true;
+                break;
+            } else if node.rhs.is_none() && *num > node.value {
+                node.rhs = Some(*num);
+                num_placed = true;
+                break;
+            }
+        }
+        if !num_placed {
+            fishbone.nodes.push(FishboneNode {
+                value: *num,
+                lhs: None,
+                rhs: None,
+            });
+        }
+    }
+    fishbone.nodes[0].value.to_string()
+        + &fishbone
+            .nodes
+            .iter()
+            .skip(1)
+            .map(|node| node.value.to_string())
+            .fold(String::new(), |acc, s| acc + &s)
+}
+
+pub fn part2(input: &[SimplifiedFishbone]) -> u64 {
+    let mut sword_values = Vec::new();
+    for sword in input {
+        let mut fishbone = Fishbone {
+            id: sword.id,
+            nodes: vec![FishboneNode {
+                value: sword.nodes[0],
+                lhs: None,
+                rhs: None,
+            }],
+        };
+        for num in sword.nodes.iter().skip(1) {
+            let mut num_placed = false;
+            for node in &mut fishbone.nodes {
+                if node.lhs.is_none() && *num < node.value {
+                    node.lhs = Some(*num);
+                    num_placed = true;
+                    break;
+                } else if node.rhs.is_none() && *num > node.value {
+                    node.rhs = Some(*num);
+                    num_placed = true;
+                    break;
+                }
+            }
+            if !num_placed {
+                fishbone.nodes.push(FishboneNode {
+                    value: *num,
+                    lhs: None,
+                    rhs: None,
+                });
+            }
+        }
+        sword_values.push(
+            (fishbone.nodes[0].value.to_string()
+                + &fishbone
+                    .nodes
+                    .iter()
+                    .skip(1)
+                    .map(|node| node.value.to_string())
+                    .fold(String::new(), |acc, s| acc + &s))
+                .parse::<u64>()
+                .unwrap(),
+        );
+    }
+    sword_values.iter().max().unwrap() - sword_values.iter().min().unwrap()
+}
+
+pub fn part3(input: &[SimplifiedFishbone]) -> u64 {
+    let mut sword_fishbones = Vec::new();
+    for sword in input {
+        let mut fishbone = Fishbone {
+            id: sword.id,
+            nodes: vec![FishboneNode {
+                value: sword.nodes[0],
+                lhs: None,
+                rhs: None,
+            }],
+        };
+        for num in sword.nodes.iter().skip(1) {
+            let mut num_placed = false;
+            for node in &mut fishbone.nodes {
+                if node.lhs.is_none() && *num < node.value {
+                    node.lhs = Some(*num);
+                    num_placed = true;
+                    break;
+                } else if node.rhs.is_none() && *num > node.value {
+                    node.rhs = Some(*num);
+                    num_placed = true;
+                    break;
+                }
+            }
+            if !num_placed {
+                fishbone.nodes.push(FishboneNode {
+                    value: *num,
+                    lhs: None,
+                    rhs: None,
+                });
+            }
+        }
+        sword_fishbones.push(fishbone);
+    }
+    // for fishbone in &sword_fishbones {
+    //     println!("{:?}", fishbone);
+    // }
+    sword_fishbones.sort_by(|a, b| {
+        let a_quality = a
+            .nodes
+            .iter()
+            .map(|node| node.value.to_string())
+            .collect::<String>()
+            .parse::<u64>()
+            .unwrap();
+        let b_quality = b
+            .nodes
+            .iter()
+            .map(|node| node.value.to_string())
+            .collect::<String>()
+            .parse::<u64>()
+            .unwrap();
+        if a_quality != b_quality {
+            b_quality.cmp(&a_quality)
+        } else {
+            for i in 0..a.nodes.len() {
+                let a_level_lhs_value = if a.nodes[i].lhs.is_none() {
+                    String::from("")
+                } else {
+                    a.nodes[i].lhs.unwrap().to_string()
+                };
+                let a_level_rhs_value = if a.nodes[i].rhs.is_none() {
+                    String::from("")
+                } else {
+                    a.nodes[i].rhs.unwrap().to_string()
+                };
+                let b_level_lhs_value = if b.nodes[i].lhs.is_none() {
+                    String::from("")
+                } else {
+                    b.nodes[i].lhs.unwrap().to_string()
+                };
+                let b_level_rhs_value = if b.nodes[i].rhs.is_none() {
+                    String::from("")
+                } else {
+                    b.nodes[i].rhs.unwrap().to_string()
+                };
+                let a_level_value =
+                    (a_level_lhs_value + &a.nodes[i].value.to_string() + &a_level_rhs_value)
+                        .parse::<u64>()
+                        .unwrap();
+                let b_level_value =
+                    (b_level_lhs_value + &b.nodes[i].value.to_string() + &b_level_rhs_value)
+                        .parse::<u64>()
+                        .unwrap();
+                if a_level_value != b_level_value {
+                    return b_level_value.cmp(&a_level_value);
+                }
+            }
+            b.id.cmp(&a.id)
+        }
+    });
+    sword_fishbones
+        .iter()
+        .enumerate()
+        .fold(0, |acc, (i, fishbone)| {
+            acc + (((i as u64) + 1) * (fishbone.id as u64))
+        })
+}
+
+pub fn extract_input_from_file(file_path: &str) -> Result<Vec<SimplifiedFishbone>, Error> {
+    parse_input(&Input::open(file_path)?)
+}
+
+pub fn parse_input(input: &Input) -> Result<Vec<SimplifiedFishbone>, Error> {
+    input
+        .lines()
+        .map(|line| {
+            let (id_str, nodes_str) = line
+                .split_once(':')
+                .ok_or_else(|| input.format_error(line, "missing ':'"))?;
+
+            let id = input.parse(id_str.trim(), "id")?;
+
+            let nodes = fields(nodes_str, ',')
+                .map(|s| input.parse(s, "number"))
+                .collect::<Result<Vec<_>, Error>>()?;
+
+            Ok(SimplifiedFishbone { id, nodes })
+        })
+        .collect()
+}
+
+pub struct Quest5;
+
+impl Solution for Quest5 {
+    type Input = Vec<SimplifiedFishbone>;
+    type Params = ();
+    type Answer1 = String;
+    type Answer2 = u64;
+    type Answer3 = u64;
+
+    fn parse_input(input: &Input) -> Result<Self::Input, Error> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Vec<SimplifiedFishbone>, _: &()) -> Result<String, Error> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Vec<SimplifiedFishbone>, _: &()) -> Result<u64, Error> {
+        Ok(part2(input))
+    }
+
+    fn part3(input: &Vec<SimplifiedFishbone>, _: &()) -> Result<u64, Error> {
+        Ok(part3(input))
+    }
+}
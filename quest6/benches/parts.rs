@@ -0,0 +1,23 @@
+//! Parse and part timings on the real inputs and the examples: `cargo bench -p quest6 --bench parts`.
+
+use common::Part;
+
+const ITERATIONS: usize = 10;
+
+fn main() {
+    common::bench::main::<quest6::Quest6>(
+        &[
+            (Part::One, "input/input1.txt", &[]),
+            (Part::Two, "input/input2.txt", &[]),
+            (Part::Three, "input/input3.txt", &[]),
+            (Part::One, "tests/input/test1.txt", &[]),
+            (Part::Two, "tests/input/test1.txt", &[]),
+            (
+                Part::Three,
+                "tests/input/test3.txt",
+                &[("max_distance", "10"), ("repeat", "1")],
+            ),
+        ],
+        ITERATIONS,
+    );
+}
@@ -0,0 +1,175 @@
+use common::{Error, Input, Params, Part, Solution, parse_param};
+
+pub fn part3(input: &[u8], max_distance: usize, repeat: usize) -> Result<usize, Error> {
+    if repeat == 0 {
+        return Err(Error::param("repeat", "must be greater than 0"));
+    }
+    if input.len() < 2 * max_distance + 1 {
+        return Err(Error::param(
+            "max_distance",
+            format!(
+                "must be at most {} for an input of {} bytes",
+                input.len().saturating_sub(1) / 2,
+                input.len()
+            ),
+        ));
+    }
+    let first_novices_input_repeat = input[input.len() - max_distance..]
+        .iter()
+        .chain(input[..max_distance * 2].iter())
+        .cloned()
+        .collect::<Vec<u8>>();
+    let last_novices_input_repeat = input[input.len() - max_distance * 2..]
+        .iter()
+        .chain(input[..max_distance].iter())
+        .cloned()
+        .collect::<Vec<u8>>();
+    let first_novices_knights_repeat = novices_knights(
+        &first_novices_input_repeat,
+        max_distance..max_distance * 2 + 1,
+        max_distance,
+    )?;
+    let last_novices_knights_repeat = novices_knights(
+        &last_novices_input_repeat,
+        max_distance..max_distance * 2 + 1,
+        max_distance,
+    )?;
+
+    Ok(
+        (repeat - 1) * (first_novices_knights_repeat + last_novices_knights_repeat)
+            + novices_knights(input, 0..max_distance, max_distance)?
+            + novices_knights(
+                input,
+                input.len() - max_distance - 1..input.len(),
+                max_distance,
+            )?
+            + novices_knights(
+                input,
+                max_distance..input.len() - max_distance - 1,
+                max_distance,
+            )? * repeat,
+    )
+}
+
+/// The knights each novice at `indices` can pair with, summed.
+fn novices_knights(
+    input: &[u8],
+    indices: std::ops::Range<usize>,
+    max_distance: usize,
+) -> Result<usize, Error> {
+    indices
+        .filter(|&index| matches!(input.get(index), Some(b'a' | b'b' | b'c')))
+        .try_fold(0, |acc, index| {
+            Ok(acc + valid_knights(input, index, max_distance)?)
+        })
+}
+
+fn valid_knights(input: &[u8], index: usize, max_distance: usize) -> Result<usize, Error> {
+    let knight_type = match input[index] {
+        b'a' => b'A',
+        b'b' => b'B',
+        b'c' => b'C',
+        _ => return Err(Error::unsolvable("Invalid knight type")),
+    };
+    let start_pos = index.saturating_sub(max_distance);
+    let end_pos = (index + max_distance).min(input.len());
+    Ok(input
+        .iter()
+        .skip(start_pos)
+        .take(end_pos - start_pos + 1)
+        .filter(|&byte| *byte == knight_type)
+        .count())
+}
+
+pub fn part1(input: &[u8]) -> usize {
+    let mut a_knights = 0;
+    input.iter().fold(0, |acc, &byte| match byte {
+        b'A' => {
+            a_knights += 1;
+            acc
+        }
+        b'a' => acc + a_knights,
+        _ => acc,
+    })
+}
+
+pub fn part2(input: &[u8]) -> usize {
+    let mut a_knights = 0;
+    let mut b_knights = 0;
+    let mut c_knights = 0;
+    input.iter().fold(0, |acc, &byte| match byte {
+        b'A' => {
+            a_knights += 1;
+            acc
+        }
+        b'a' => acc + a_knights,
+        b'B' => {
+            b_knights += 1;
+            acc
+        }
+        b'b' => acc + b_knights,
+        b'C' => {
+            c_knights += 1;
+            acc
+        }
+        b'c' => acc + c_knights,
+        _ => acc,
+    })
+}
+
+pub fn extract_input_from_file(file_path: &str) -> Result<Vec<u8>, Error> {
+    Ok(parse_input(&Input::open(file_path)?))
+}
+
+pub fn parse_input(input: &Input) -> Vec<u8> {
+    input.trimmed().as_bytes().to_vec()
+}
+
+pub struct Quest6Params {
+    pub max_distance: usize,
+    pub repeat: usize,
+}
+
+impl Params for Quest6Params {
+    fn for_part(_part: Part) -> Self {
+        Quest6Params {
+            max_distance: 1000,
+            repeat: 1000,
+        }
+    }
+
+    fn set(&mut self, key: &str, value: &str) -> Result<(), Error> {
+        match key {
+            "max_distance" => self.max_distance = parse_param(key, value)?,
+            "repeat" => self.repeat = parse_param(key, value)?,
+            _ => return Err(Error::param(key, "unknown param")),
+        }
+        Ok(())
+    }
+}
+
+pub struct Quest6;
+
+impl Solution for Quest6 {
+    type Input = Vec<u8>;
+    type Params = Quest6Params;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Answer3 = usize;
+
+    fn parse_input(input: &Input) -> Result<Self::Input, Error> {
+        Ok(parse_input(input))
+    }
+
+    fn part1(input: &Vec<u8>, _: &Quest6Params) -> Result<usize, Error> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Vec<u8>, _: &Quest6Params) -> Result<usize, Error> {
+        Ok(part2(input))
+    }
+
+    fn part3(input: &Vec<u8>, params: &Quest6Params) -> Result<usize, Error> {
+        part3(input, params.max_distance, params.repeat)
+    }
+}
@@ -0,0 +1,21 @@
+use common::Error;
+use quest6::part3;
+
+#[test]
+fn max_distance_longer_than_the_input_is_rejected() {
+    for (input, max_distance) in [(&b"AaBb"[..], 2), (b"a", 1), (b"", 0)] {
+        let error = part3(input, max_distance, 1).unwrap_err();
+        assert!(
+            matches!(&error, Error::Param { key, .. } if key == "max_distance"),
+            "{:?}",
+            error
+        );
+    }
+}
+
+#[test]
+fn max_distance_fitting_the_input_is_solved() {
+    assert_eq!(part3(b"a", 0, 3).unwrap(), 0);
+    assert_eq!(part3(b"AaBbC", 2, 1).unwrap(), 2);
+    assert_eq!(part3(b"AaBbC", 2, 2).unwrap(), 4);
+}
@@ -0,0 +1,20 @@
+//! Parse and part timings on the real inputs and the examples: `cargo bench -p quest7 --bench parts`.
+
+use common::Part;
+
+const ITERATIONS: usize = 10;
+
+fn main() {
+    common::bench::main::<quest7::Quest7>(
+        &[
+            (Part::One, "input/input1.txt", &[]),
+            (Part::Two, "input/input2.txt", &[]),
+            (Part::Three, "input/input3.txt", &[]),
+            (Part::One, "tests/input/test1.txt", &[]),
+            (Part::Two, "tests/input/test2.txt", &[]),
+            (Part::Three, "tests/input/test3.txt", &[]),
+            (Part::Three, "tests/input/test4.txt", &[]),
+        ],
+        ITERATIONS,
+    );
+}
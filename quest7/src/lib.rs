@@ -0,0 +1,186 @@
+use common::input::fields;
+use common::{Error, Input, Solution};
+use core::{clone::Clone, iter::Iterator};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug)]
+pub struct InstructionSet {
+    pub names: Vec<String>,
+    pub rules: HashMap<u8, HashSet<u8>>,
+}
+
+pub fn part1(input: &InstructionSet) -> Option<String> {
+    input
+        .names
+        .iter()
+        .find(|name| is_name_valid(name, &input.rules))
+        .cloned()
+}
+
+pub fn part2(input: &InstructionSet) -> u32 {
+    input
+        .names
+        .iter()
+        .enumerate()
+        .fold(0, |acc, (index, name)| {
+            if is_name_valid(name, &input.rules) {
+                acc + (index + 1) as u32
+            } else {
+                acc
+            }
+        })
+}
+
+// 7 to 11 letters
+// only valid names
+pub fn part3(input: &InstructionSet) -> u64 {
+    let valid_names = input
+        .names
+        .iter()
+        .filter(|name| is_name_valid(name, &input.rules))
+        .collect::<Vec<&String>>();
+    let filtered_valid_names = filter_extended_names(&valid_names);
+    filtered_valid_names.iter().fold(0, |acc, name| {
+        acc + count_possible_unique_names(7, 11, name.as_bytes(), &input.rules)
+    })
+}
+
+fn filter_extended_names(names: &Vec<&String>) -> Vec<String> {
+    let mut names_mut: Vec<String> = names
+        .iter()
+        .map(|name| name.to_string())
+        .collect::<Vec<String>>();
+    names_mut.sort_by_key(|s| s.len());
+    let mut result = vec![names_mut[0].clone()];
+    for i in 0..names_mut.len() - 1 {
+        let cur_name = &names_mut[names_mut.len() - 1 - i];
+        if !names_mut[0..names_mut.len() - 2 - i]
+            .iter()
+            .any(|name| cur_name.starts_with(name))
+        {
+            result.push(cur_name.clone());
+        }
+    }
+    result
+}
+
+fn count_possible_unique_names(
+    min_chars: u8,
+    max_chars: u8,
+    base: &[u8],
+    rules: &HashMap<u8, HashSet<u8>>,
+) -> u64 {
+    internal_count_possible_unique_names(
+        max_chars,
+        *base.iter().last().unwrap(),
+        base.len() as u8,
+        rules,
+    ) - internal_count_possible_unique_names(
+        min_chars - 1,
+        *base.iter().last().unwrap(),
+        base.len() as u8,
+        rules,
+    )
+}
+
+fn internal_count_possible_unique_names(
+    max_chars: u8,
+    last_byte: u8,
+    chars_len: u8,
+    rules: &HashMap<u8, HashSet<u8>>,
+) -> u64 {
+    if chars_len == max_chars {
+        1
+    } else if let Some(bytes) = rules.get(&last_byte) {
+        bytes.iter().fold(1, |acc, byte| {
+            acc + internal_count_possible_unique_names(max_chars, *byte, chars_len + 1, rules)
+        })
+    } else {
+        1
+    }
+}
+
+fn is_name_valid(name: &str, rules: &HashMap<u8, HashSet<u8>>) -> bool {
+    for name_letter_index in 0..name.len() - 1 {
+        let current_letter = name.as_bytes()[name_letter_index];
+        let next_letter = name.as_bytes()[name_letter_index + 1];
+        if !rules[&current_letter].contains(&next_letter) {
+            return false;
+        }
+    }
+    true
+}
+
+pub fn extract_input_from_file(file_path: &str) -> Result<InstructionSet, Error> {
+    parse_input(&Input::open(file_path)?)
+}
+
+pub fn parse_input(input: &Input) -> Result<InstructionSet, Error> {
+    let mut sections = input.sections();
+    let names = fields(
+        sections
+            .next()
+            .ok_or_else(|| input.format_error(input.text(), "no names line found"))?,
+        ',',
+    )
+    .map(|s| s.to_string())
+    .collect::<Vec<_>>();
+
+    let letter_rules = sections
+        .next()
+        .into_iter()
+        .flat_map(str::lines)
+        .map(|line| {
+            let (left, right) = line
+                .split_once(" > ")
+                .ok_or_else(|| input.format_error(line, "expected a rule like \"a > b,c\""))?;
+
+            let key = left
+                .trim()
+                .bytes()
+                .next()
+                .ok_or_else(|| input.format_error(left, "no key letter in rule"))?;
+
+            let values = fields(right, ',')
+                .map(|s| {
+                    s.bytes()
+                        .next()
+                        .ok_or_else(|| input.format_error(s, "empty letter in rule values"))
+                })
+                .collect::<Result<HashSet<u8>, Error>>()?;
+
+            Ok((key, values))
+        })
+        .collect::<Result<HashMap<u8, HashSet<u8>>, Error>>()?;
+
+    Ok(InstructionSet {
+        names,
+        rules: letter_rules,
+    })
+}
+
+pub struct Quest7;
+
+impl Solution for Quest7 {
+    type Input = InstructionSet;
+    type Params = ();
+    type Answer1 = String;
+    type Answer2 = u32;
+    type Answer3 = u64;
+
+    fn parse_input(input: &Input) -> Result<Self::Input, Error> {
+        parse_input(input)
+    }
+
+    fn part1(input: &InstructionSet, _: &()) -> Result<String, Error> {
+        part1(input).ok_or_else(|| Error::unsolvable("No valid name found"))
+    }
+
+    fn part2(input: &InstructionSet, _: &()) -> Result<u32, Error> {
+        Ok(part2(input))
+    }
+
+    fn part3(input: &InstructionSet, _: &()) -> Result<u64, Error> {
+        Ok(part3(input))
+    }
+}
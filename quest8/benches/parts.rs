@@ -0,0 +1,19 @@
+//! Parse and part timings on the real inputs and the examples: `cargo bench -p quest8 --bench parts`.
+
+use common::Part;
+
+const ITERATIONS: usize = 10;
+
+fn main() {
+    common::bench::main::<quest8::Quest8>(
+        &[
+            (Part::One, "input/input1.txt", &[]),
+            (Part::Two, "input/input2.txt", &[]),
+            (Part::Three, "input/input3.txt", &[]),
+            (Part::One, "tests/input/test1.txt", &[("nails", "8")]),
+            (Part::Two, "tests/input/test2.txt", &[]),
+            (Part::Three, "tests/input/test3.txt", &[("nails", "8")]),
+        ],
+        ITERATIONS,
+    );
+}
@@ -0,0 +1,123 @@
+use common::input::fields;
+use common::{Error, Input, Params, Part, Solution, parse_param};
+use itertools::Itertools;
+
+pub struct Line {
+    pub a: u16,
+    pub b: u16,
+}
+
+impl Line {
+    pub fn new(a: u16, b: u16) -> Self {
+        Line {
+            a: a.min(b),
+            b: a.max(b),
+        }
+    }
+    pub fn cuts(&self, line: &Line) -> bool {
+        ((self.a < line.b && self.a > line.a) && (self.b > line.b))
+            || ((self.b > line.a && self.b < line.b) && (self.a < line.a))
+    }
+}
+
+pub fn extract_input_from_file(file_path: &str) -> Result<Vec<u16>, Error> {
+    parse_input(&Input::open(file_path)?)
+}
+
+pub fn parse_input(input: &Input) -> Result<Vec<u16>, Error> {
+    fields(input.trimmed(), ',')
+        .map(|n| {
+            n.parse::<u16>()
+                .map_err(|e| input.invalid_value(n, format!("couldn't parse number: {}", e)))
+        })
+        .collect()
+}
+
+pub fn part3(input: &[u16], nails: u16) -> u32 {
+    let lines = input
+        .windows(2)
+        .map(|window| Line::new(window[0], window[1]))
+        .collect::<Vec<Line>>();
+    let mut result = 0;
+    for i in 0..nails {
+        for j in i + 1..=nails {
+            result = result.max(
+                lines
+                    .iter()
+                    .filter(|line| Line { a: i, b: j }.cuts(line) || (line.a == i && line.b == j))
+                    .count() as u32,
+            );
+        }
+    }
+    result
+}
+
+pub fn part1(input: &[u16], nails: u16) -> u32 {
+    input
+        .iter()
+        .tuple_windows()
+        .filter(|(a, b)| *a.max(b) - *a.min(b) == nails / 2)
+        .count() as u32
+}
+
+pub fn part2(input: &[u16]) -> u64 {
+    let mut result = 0;
+    let lines = input
+        .windows(2)
+        .map(|window| Line::new(window[0], window[1]))
+        .collect::<Vec<Line>>();
+    for i in 0..lines.len() {
+        result += lines
+            .iter()
+            .skip(i + 1)
+            .filter(|line| line.cuts(&lines[i]))
+            .count() as u64;
+    }
+    result
+}
+
+pub struct Quest8Params {
+    pub nails: u16,
+}
+
+impl Params for Quest8Params {
+    fn for_part(part: Part) -> Self {
+        Quest8Params {
+            nails: if part == Part::One { 32 } else { 256 },
+        }
+    }
+
+    fn set(&mut self, key: &str, value: &str) -> Result<(), Error> {
+        match key {
+            "nails" => self.nails = parse_param(key, value)?,
+            _ => return Err(Error::param(key, "unknown param")),
+        }
+        Ok(())
+    }
+}
+
+pub struct Quest8;
+
+impl Solution for Quest8 {
+    type Input = Vec<u16>;
+    type Params = Quest8Params;
+    type Answer1 = u32;
+    type Answer2 = u64;
+    type Answer3 = u32;
+
+    fn parse_input(input: &Input) -> Result<Self::Input, Error> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Vec<u16>, params: &Quest8Params) -> Result<u32, Error> {
+        Ok(part1(input, params.nails))
+    }
+
+    fn part2(input: &Vec<u16>, _: &Quest8Params) -> Result<u64, Error> {
+        Ok(part2(input))
+    }
+
+    fn part3(input: &Vec<u16>, params: &Quest8Params) -> Result<u32, Error> {
+        Ok(part3(input, params.nails))
+    }
+}
@@ -0,0 +1,185 @@
+use common::{Error, Input, Solution};
+use std::collections::{HashSet, VecDeque};
+
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub struct DeoxyribonucleicAcid {
+    pub id: u32,
+    pub sequence: Vec<u8>,
+}
+
+impl DeoxyribonucleicAcid {
+    pub fn find_parents(
+        &self,
+        dragonducks: &[DeoxyribonucleicAcid],
+    ) -> Option<[DeoxyribonucleicAcid; 2]> {
+        for i in 0..dragonducks.len() {
+            if dragonducks[i].id == self.id {
+                continue;
+            }
+            for j in i + 1..dragonducks.len() {
+                if dragonducks[j].id == self.id {
+                    continue;
+                }
+                let parents = [&dragonducks[i], &dragonducks[j]];
+                if self.are_valid_parents(&parents) {
+                    return Some([dragonducks[i].clone(), dragonducks[j].clone()]);
+                }
+            }
+        }
+        None
+    }
+
+    pub fn are_valid_parents(&self, parents: &[&DeoxyribonucleicAcid; 2]) -> bool {
+        self.sequence.iter().enumerate().all(|(i, element)| {
+            *element == parents[0].sequence[i] || *element == parents[1].sequence[i]
+        })
+    }
+
+    pub fn count_similarity_degree(&self, parent: &DeoxyribonucleicAcid) -> usize {
+        self.sequence
+            .iter()
+            .enumerate()
+            .filter(|(i, element)| **element == parent.sequence[*i])
+            .count()
+    }
+}
+
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub struct Dragonduck {
+    pub dna: DeoxyribonucleicAcid,
+    pub parents: Option<[DeoxyribonucleicAcid; 2]>,
+}
+
+impl Dragonduck {
+    pub fn find_children(&self, dragonducks: &[Dragonduck]) -> Vec<Dragonduck> {
+        dragonducks
+            .iter()
+            .filter(|dragonduck| {
+                dragonduck
+                    .parents
+                    .as_ref()
+                    .is_some_and(|parents| parents.iter().any(|parent| parent.id == self.dna.id))
+            })
+            .cloned()
+            .collect()
+    }
+
+    pub fn build_family(&self, dragonducks: &[Dragonduck]) -> Result<HashSet<Dragonduck>, Error> {
+        let mut result: HashSet<Dragonduck> = HashSet::new();
+        let mut container: VecDeque<Dragonduck> = VecDeque::from([self.clone()]);
+        while !container.is_empty() {
+            let current = container.pop_front().unwrap();
+            result.insert(current.clone());
+            if let Some(parents) = &current.parents {
+                for parent in parents {
+                    if !result.iter().any(|r| r.dna.id == parent.id) {
+                        container.push_back(
+                            dragonducks
+                                .iter()
+                                .find(|dragonduck| dragonduck.dna.id == parent.id)
+                                .ok_or_else(|| Error::unsolvable("Couldn't find parent by id"))?
+                                .clone(),
+                        );
+                    }
+                }
+            }
+            let children = current.find_children(dragonducks);
+            for child in children {
+                if !result.iter().any(|r| r.dna.id == child.dna.id) {
+                    container.push_back(child);
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
+pub fn part3(input: &[DeoxyribonucleicAcid]) -> Result<usize, Error> {
+    let dragonducks: Vec<Dragonduck> = input
+        .iter()
+        .map(|dragonduck| Dragonduck {
+            dna: dragonduck.clone(),
+            parents: dragonduck.find_parents(input),
+        })
+        .collect();
+    let oldest_dragonducks: Vec<&Dragonduck> = dragonducks
+        .iter()
+        .filter(|dragonduck| dragonduck.parents.is_none())
+        .collect();
+
+    Ok(oldest_dragonducks
+        .iter()
+        .filter_map(|od| od.build_family(&dragonducks).ok())
+        .max_by_key(|od| od.len())
+        .ok_or_else(|| Error::unsolvable("No dragoduck families"))?
+        .iter()
+        .fold(0, |acc, od| od.dna.id as usize + acc))
+}
+
+pub fn part2(input: &[DeoxyribonucleicAcid]) -> usize {
+    input.iter().fold(0, |acc, dragonduck| {
+        if let Some(parents) = dragonduck.find_parents(input) {
+            dragonduck.count_similarity_degree(&parents[0])
+                * dragonduck.count_similarity_degree(&parents[1])
+                + acc
+        } else {
+            acc
+        }
+    })
+}
+
+pub fn part1(input: &[DeoxyribonucleicAcid]) -> Result<usize, Error> {
+    let child = input
+        .iter()
+        .find(|deoxyribonucleic_acid| deoxyribonucleic_acid.find_parents(input).is_some())
+        .ok_or_else(|| Error::unsolvable("Couldn't find a valid child"))?;
+    let parents = child
+        .find_parents(input)
+        .ok_or_else(|| Error::unsolvable("Couldn't find parents"))?;
+    Ok(child.count_similarity_degree(&parents[0]) * child.count_similarity_degree(&parents[1]))
+}
+
+pub fn extract_input_from_file(file_path: &str) -> Result<Vec<DeoxyribonucleicAcid>, Error> {
+    parse_input(&Input::open(file_path)?)
+}
+
+pub fn parse_input(input: &Input) -> Result<Vec<DeoxyribonucleicAcid>, Error> {
+    input
+        .lines()
+        .map(|line| {
+            let (id, sequence) = line
+                .split_once(':')
+                .ok_or_else(|| input.format_error(line, "couldn't split line with ':'"))?;
+            Ok(DeoxyribonucleicAcid {
+                id: input.parse(id, "ID")?,
+                sequence: sequence.bytes().collect(),
+            })
+        })
+        .collect()
+}
+
+pub struct Quest9;
+
+impl Solution for Quest9 {
+    type Input = Vec<DeoxyribonucleicAcid>;
+    type Params = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Answer3 = usize;
+
+    fn parse_input(input: &Input) -> Result<Self::Input, Error> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Vec<DeoxyribonucleicAcid>, _: &()) -> Result<usize, Error> {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<DeoxyribonucleicAcid>, _: &()) -> Result<usize, Error> {
+        Ok(part2(input))
+    }
+
+    fn part3(input: &Vec<DeoxyribonucleicAcid>, _: &()) -> Result<usize, Error> {
+        part3(input)
+    }
+}